@@ -1,19 +1,14 @@
-use log::{debug, info, warn, error};
-use itertools::Itertools;
-use env_logger;
-use regex::CaptureMatches;
-
-use std::collections::{HashMap, HashSet};
-use std::fmt::Debug;
 use once_cell::sync::Lazy;
 
-use advent::{InputSnake, FromRegex};
+use std::fmt::Display;
+
+use advent::day::Day;
 
-/// ------
-/// Part 1
-/// ------
+// ------
+// Part 1
+// ------
 
-fn find_calibration<'a, I, T>(snake: I) -> u32
+fn find_calibration<I, T>(snake: I) -> u32
 where
     I: Iterator<Item = T>,
     T: AsRef<str> {
@@ -29,28 +24,11 @@ where
     total_calibration
 }
 
-fn part_one_test() {
-    let input = r#"
-1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet
-"#;
-
-    info!("sum calibration: {}", find_calibration(input.split_whitespace()));
-}
+// ------
+// Part 2
+// ------
 
-fn part_one() {
-    let input = InputSnake::new("input");
-    let digits = input.snake();
-    info!("Part One: {:?}", find_calibration(digits));
-}
-
-/// ------
-/// Part 2
-/// ------
-
-const DIGITS: Lazy<Vec<(&'static str, &'static str)>> = Lazy::new(|| {
+static DIGITS: Lazy<Vec<(&'static str, &'static str)>> = Lazy::new(|| {
     vec!(
         ("one", "1"),
         ("two", "2"),
@@ -76,10 +54,10 @@ fn replace_text_digits(text: &str) -> String {
         }
     }
 
-    return format!("{}{}", &text[..1], replace_text_digits(&text[1..]));
+    format!("{}{}", &text[..1], replace_text_digits(&text[1..]))
 }
 
-fn find_calibration_two<'a, I, T>(snake: I) -> u32
+fn find_calibration_two<I, T>(snake: I) -> u32
 where
     I: Iterator<Item = T>,
     T: AsRef<str> {
@@ -96,8 +74,48 @@ where
     total_calibration
 }
 
-fn part_two_test() {
-    let input = r#"
+// ---
+// Day
+// ---
+
+pub struct Day1;
+
+impl Day for Day1 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().collect()
+    }
+
+    fn part_one(lines: &Self::Input<'_>) -> impl Display {
+        find_calibration(lines.iter())
+    }
+
+    fn part_two(lines: &Self::Input<'_>) -> impl Display {
+        find_calibration_two(lines.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent::InputSnake;
+
+    #[test]
+    fn part_one_example() {
+        let input = InputSnake::from_text(r#"
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+"#);
+
+        assert_eq!(find_calibration(input.snake()), 142);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = InputSnake::from_text(r#"
 two1nine
 eightwothree
 abcone2threexyz
@@ -105,32 +123,13 @@ xtwone3four
 4nineeightseven2
 zoneight234
 7pqrstsixteen
-"#;
+"#);
 
-    info!("sum calibration: {}", find_calibration_two(input.split_whitespace()));
-}
-
-fn part_two() {
-    let input = InputSnake::new("input");
-    let digits = input.snake();
-    info!("Part Two: {:?}", find_calibration_two(digits));
+        assert_eq!(find_calibration_two(input.snake()), 281);
+    }
 }
 
-// ----
-// Main
-// ----
-
-fn main() {
-    env_logger::init_from_env(env_logger::Env::default()
-        .filter_or(env_logger::DEFAULT_FILTER_ENV, "debug"));
-
-    info!("Part 1 Test");
-    part_one_test();
-    info!("Part 1");
-    part_one();
- 
-    info!("Part 2 Test");
-    part_two_test();
-    info!("Part 2");
-    part_two();
-}
+advent::examples!(Day1, 2023, 1;
+    part_one: test_input => 142;
+    part_two: test_input_2 => 281;
+);
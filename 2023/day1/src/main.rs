@@ -0,0 +1,21 @@
+use log::info;
+
+use advent::InputSnake;
+use advent::day::{current_input_dir, Day};
+
+use aoc2023_day1::Day1;
+
+fn main() {
+    env_logger::init_from_env(env_logger::Env::default()
+        .filter_or(env_logger::DEFAULT_FILTER_ENV, "debug"));
+
+    let inputs = current_input_dir(2023, 1);
+    let input = InputSnake::new(inputs.join("input")).no_snake();
+    let input = Day1::parse(&input);
+
+    info!("Part 1");
+    info!("{}", Day1::part_one(&input));
+
+    info!("Part 2");
+    info!("{}", Day1::part_two(&input));
+}
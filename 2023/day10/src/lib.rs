@@ -0,0 +1,107 @@
+use advent::grid::{Coord, Direction, CARDINAL_DIRECTIONS};
+use advent::grid::tiles::{propagate, TileTable};
+use log::debug;
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use advent::{FromGridChar, InputSnake, TileGrid};
+use advent::day::Day;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, FromGridChar)]
+pub enum Tile {
+    /// A vertical pipe connecting north and south.
+    #[tile('|')]
+    Vertical,
+    /// A horizontal pipe connecting east and west.
+    #[tile('-')]
+    Horizontal,
+    /// A 90-degree bend connecting north and east.
+    #[tile('L')]
+    NorthEast,
+    /// A 90-degree bend connecting north and west.
+    #[tile('J')]
+    NorthWest,
+    /// A 90-degree bend connecting south and west.
+    #[tile('7')]
+    SouthWest,
+    /// A 90-degree bend connecting south and east.
+    #[tile('F')]
+    SouthEast,
+    /// Ground; there is no pipe in this tile.
+    #[tile('.')]
+    Ground,
+    /// The start, which is on a pipe of unknown shape.
+    #[tile('S', marker = "start")]
+    Start,
+}
+
+impl Tile {
+    /// Return the directions the tile connects towards.
+    fn connections(self) -> &'static [Direction] {
+        match self {
+            Tile::Vertical => &[Direction::Up, Direction::Down],
+            Tile::Horizontal => &[Direction::Left, Direction::Right],
+            Tile::NorthEast => &[Direction::Up, Direction::Right],
+            Tile::NorthWest => &[Direction::Up, Direction::Left],
+            Tile::SouthWest => &[Direction::Down, Direction::Left],
+            Tile::SouthEast => &[Direction::Down, Direction::Right],
+            Tile::Ground => &[],
+            Tile::Start => &CARDINAL_DIRECTIONS,
+        }
+    }
+}
+
+/// Return how the pipes connect, where the start connects every way.
+fn pipes() -> TileTable<Tile> {
+    use Tile::*;
+    [Vertical, Horizontal, NorthEast, NorthWest, SouthWest, SouthEast, Ground, Start].into_iter()
+        .fold(TileTable::new(), |table, tile| table.with_pipe(tile, tile.connections()))
+}
+
+// ------
+// Part 1
+// ------
+
+fn find_steps(tiles: &TileGrid<Tile>) -> usize {
+    // the furthest point of the loop is halfway around it
+    find_path(tiles).len() / 2
+}
+
+// ------
+// Part 2
+// ------
+
+/// Return the cells of the loop through the start.
+pub fn find_path(tiles: &TileGrid<Tile>) -> BTreeSet<Coord> {
+    let start = tiles.marker("start").expect("One start");
+    let propagation = propagate(&tiles.grid, &pipes(), start, Direction::Up);
+    debug!("loop of {} cells from {:?}", propagation.loops.len(), start);
+    propagation.loops
+}
+
+// ---
+// Day
+// ---
+
+pub struct Day10;
+
+impl Day for Day10 {
+    type Input<'a> = TileGrid<Tile>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        InputSnake::from_text(input).typed_grid_snake().unwrap()
+    }
+
+    fn part_one(tiles: &Self::Input<'_>) -> impl Display {
+        find_steps(tiles)
+    }
+
+    fn part_two(_tiles: &Self::Input<'_>) -> impl Display {
+        "unsolved"
+    }
+}
+
+advent::examples!(Day10, 2023, 10;
+    part_one: test_input => 4, test_input_2 => 8;
+);
@@ -0,0 +1,30 @@
+use log::info;
+
+use advent::{FromGridChar, InputSnake};
+use advent::day::{current_input_dir, Day};
+
+use aoc2023_day10::{find_path, Day10, Tile};
+
+fn main() {
+    env_logger::init_from_env(env_logger::Env::default()
+        .filter_or(env_logger::DEFAULT_FILTER_ENV, "info"));
+
+    let inputs = current_input_dir(2023, 10);
+    let test_input = InputSnake::new(inputs.join("test_input")).no_snake();
+    let test_input = Day10::parse(&test_input);
+    let input = InputSnake::new(inputs.join("input")).no_snake();
+    let input = Day10::parse(&input);
+
+    // info!("Part One Test");
+    // println!("{}", test_input.grid);
+    // info!("{}", Day10::part_one(&test_input));
+    // info!("Part One");
+    // info!("{}", Day10::part_one(&input));
+
+    info!("Part Two Test");
+    let path = find_path(&test_input);
+    test_input.grid.draw(|coord, tile| if path.contains(&coord) { tile.map(Tile::to_grid_char) } else { Some('.') });
+    info!("{}", Day10::part_two(&test_input));
+    info!("Part Two");
+    info!("{}", Day10::part_two(&input));
+}
@@ -0,0 +1,147 @@
+use std::fmt::{Debug, Display};
+
+use advent::{FromRegex, RegexLineError};
+use advent::day::Day;
+
+#[derive(Debug)]
+struct Set {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    sets: Vec<Set>,
+}
+
+#[derive(Debug, FromRegex)]
+#[regex(r"^Game (?P<id>\d+): (?P<sets>.*)$")]
+struct GameLine {
+    id: u32,
+    sets: String,
+}
+
+/// The cubes of each color in a set, such as `3 blue, 4 red`, where a missing color is none.
+#[derive(Debug, FromRegex)]
+#[regex(r"(?P<red>\d+) red|(?P<green>\d+) green|(?P<blue>\d+) blue")]
+struct SetCubes {
+    red: Vec<u32>,
+    green: Vec<u32>,
+    blue: Vec<u32>,
+}
+
+fn parse_games<T, I>(snake: I) -> Vec<Game>
+where
+    T: AsRef<str> + std::fmt::Display,
+    I: Iterator<Item = T> {
+
+    snake.enumerate()
+        .map(|(i, line)| {
+            let line = line.as_ref();
+            let game = GameLine::from_line(line)
+                .unwrap_or_else(|error| panic!("{}", RegexLineError { line_number: i + 1, line: line.to_string(), error }));
+
+            let sets = game.sets.split(';')
+                .map(|set| {
+                    let cubes = SetCubes::from_line(set).expect("Set has cubes");
+                    Set {
+                        red: cubes.red.iter().sum(),
+                        green: cubes.green.iter().sum(),
+                        blue: cubes.blue.iter().sum(),
+                    }
+                })
+                .collect();
+
+            Game {
+                id: game.id,
+                sets,
+            }
+        })
+        .collect()
+}
+
+const RED_CUBES: u32 = 12;
+const GREEN_CUBES: u32 = 13;
+const BLUE_CUBES: u32 = 14;
+
+// ------
+// Part 1
+// ------
+
+fn possible_game_id_sum(games: &[Game]) -> u32 {
+    games.iter()
+        .filter(|game| game.sets.iter()
+            .all(|set| set.red <= RED_CUBES && set.green <= GREEN_CUBES && set.blue <= BLUE_CUBES))
+        .map(|game| game.id)
+        .sum()
+}
+
+// ------
+// Part 2
+// ------
+
+fn cube_power_sum(games: &[Game]) -> u32 {
+    games.iter()
+        .map(|game| {
+            let max_red = game.sets.iter().map(|set| set.red).max().expect("red to exist in set");
+            let max_green = game.sets.iter().map(|set| set.green).max().expect("green to exist in set");
+            let max_blue = game.sets.iter().map(|set| set.blue).max().expect("blue to exist in set");
+            max_red * max_green * max_blue
+        })
+        .sum()
+}
+
+// ---
+// Day
+// ---
+
+pub struct Day2;
+
+impl Day for Day2 {
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_games(input.lines())
+    }
+
+    fn part_one(games: &Self::Input<'_>) -> impl Display {
+        possible_game_id_sum(games)
+    }
+
+    fn part_two(games: &Self::Input<'_>) -> impl Display {
+        cube_power_sum(games)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent::InputSnake;
+
+    const EXAMPLE: &str = r#"
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"#;
+
+    #[test]
+    fn part_one_example() {
+        let games = parse_games(InputSnake::from_text(EXAMPLE).snake());
+        assert_eq!(possible_game_id_sum(&games), 8);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let games = parse_games(InputSnake::from_text(EXAMPLE).snake());
+        assert_eq!(cube_power_sum(&games), 2286);
+    }
+}
+
+advent::examples!(Day2, 2023, 2;
+    part_one: test_input => 8;
+    part_two: test_input => 2286;
+);
@@ -0,0 +1,21 @@
+use log::info;
+
+use advent::InputSnake;
+use advent::day::{current_input_dir, Day};
+
+use aoc2023_day2::Day2;
+
+fn main() {
+    env_logger::init_from_env(env_logger::Env::default()
+        .filter_or(env_logger::DEFAULT_FILTER_ENV, "debug"));
+
+    let inputs = current_input_dir(2023, 2);
+    let input = InputSnake::new(inputs.join("input")).no_snake();
+    let input = Day2::parse(&input);
+
+    info!("Part One");
+    info!("{}", Day2::part_one(&input));
+
+    info!("Part Two");
+    info!("{}", Day2::part_two(&input));
+}
@@ -0,0 +1,115 @@
+use log::debug;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display};
+
+use advent::InputSnake;
+use advent::day::Day;
+use advent::grid::{Coord, Grid};
+
+// ------
+// Part 1
+// ------
+
+fn is_symbol(c: char) -> bool {
+    if c.is_ascii_digit() {
+        return false;
+    }
+
+    if c == '.' {
+        return false;
+    }
+
+    true
+}
+
+fn find_part_numbers(grid: &Grid<char>) -> Vec<u32> {
+    // a number is a part number if any cell around its digits is a symbol
+    grid.number_spans().into_iter()
+        .filter(|(_, span)| span.neighbors(grid, true).into_iter().any(|(_, &c)| is_symbol(c)))
+        .map(|(number, _)| number as u32)
+        .collect()
+}
+
+// ------
+// Part 2
+// ------
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+struct PartNumber {
+    coord: Coord,
+    number: u32,
+}
+
+#[derive(Debug)]
+struct Gear {
+    coord: Coord,
+    part_numbers: (PartNumber, PartNumber),
+}
+
+impl Gear {
+    pub fn gear_ratio(&self) -> u32 {
+        self.part_numbers.0.number * self.part_numbers.1.number
+    }
+}
+
+fn find_gears(grid: &Grid<char>) -> Vec<Gear> {
+    // find the gears adjacent to each number, keyed by where the number starts
+    let gears_by_part_number: HashMap<PartNumber, HashSet<Coord>> = grid.number_spans().into_iter()
+        .map(|(number, span)| {
+            let part_number = PartNumber {
+                coord: span.start,
+                number: number as u32,
+            };
+            let adjacent_gears = span.neighbors(grid, true).into_iter()
+                .filter(|(_, &c)| c == '*')
+                .map(|(coord, _)| coord)
+                .collect();
+            (part_number, adjacent_gears)
+        })
+        .collect();
+
+    // reverse the map, populating the gears adjancent to each part number
+    let part_numbers_by_gear: HashMap<Coord, Vec<PartNumber>> = gears_by_part_number.into_iter()
+        .flat_map(|(k, vs)| vs.into_iter().map(move |v| (v, k)))
+        .fold(HashMap::new(), |mut acc, (k, v)| {
+            acc.entry(k).or_default().push(v);
+            acc
+        });
+
+    // populate the gear struct with the actual gears (only consist of two part numbers)
+    part_numbers_by_gear.into_iter()
+        .filter(|(_coord, part_nos)| part_nos.len() == 2)
+        .map(|(coord, part_nos)| Gear {
+            coord,
+            part_numbers: (*part_nos.first().unwrap(), *part_nos.get(1).unwrap())
+        })
+        .inspect(|gear| debug!("gear at {:?}: {:?}", gear.coord, gear.part_numbers))
+        .collect()
+}
+
+// ---
+// Day
+// ---
+
+pub struct Day3;
+
+impl Day for Day3 {
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        InputSnake::from_text(input).grid_snake()
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> impl Display {
+        find_part_numbers(grid).iter().sum::<u32>()
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> impl Display {
+        find_gears(grid).iter().map(|g| g.gear_ratio()).sum::<u32>()
+    }
+}
+
+advent::examples!(Day3, 2023, 3;
+    part_one: test_input => 4361, test_input_2 => 46;
+    part_two: test_input => 467835, test_input_2 => 408;
+);
@@ -0,0 +1,27 @@
+use log::info;
+
+use advent::InputSnake;
+use advent::day::{current_input_dir, Day};
+
+use aoc2023_day3::Day3;
+
+fn main() {
+    env_logger::init_from_env(env_logger::Env::default()
+        .filter_or(env_logger::DEFAULT_FILTER_ENV, "info"));
+
+    let inputs = current_input_dir(2023, 3);
+    let test_input = InputSnake::new(inputs.join("test_input")).no_snake();
+    let test_input = Day3::parse(&test_input);
+    let input = InputSnake::new(inputs.join("input")).no_snake();
+    let input = Day3::parse(&input);
+
+    info!("Part One Test");
+    info!("{}", Day3::part_one(&test_input));
+    info!("Part One");
+    info!("{}", Day3::part_one(&input));
+
+    info!("Part Two Test");
+    info!("{}", Day3::part_two(&test_input));
+    info!("Part Two");
+    info!("{}", Day3::part_two(&input));
+}
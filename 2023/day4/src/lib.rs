@@ -0,0 +1,100 @@
+use log::debug;
+use nom::character::complete::{char, space1};
+use nom::sequence::{separated_pair, tuple};
+use nom::IResult;
+
+use std::fmt::{Debug, Display};
+use std::ops::Range;
+
+use advent::day::Day;
+use advent::parse::{lines, numbered, parse_all, unsigned_list};
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    id: u32,
+    instances: u32,
+    winning_numbers: Vec<u32>,
+    numbers: Vec<u32>,
+}
+
+fn parse_card(input: &str) -> IResult<&str, Card> {
+    let (input, id) = numbered("Card")(input)?;
+    let (input, (winning_numbers, numbers)) = separated_pair(
+        unsigned_list,
+        tuple((space1, char('|'), space1)),
+        unsigned_list,
+    )(input)?;
+
+    Ok((input, Card {
+        id,
+        instances: 1,
+        winning_numbers,
+        numbers,
+    }))
+}
+
+fn card_points(card: &Card) -> u32 {
+    let num_overlap = card.winning_numbers.iter()
+        .filter(|num| card.numbers.contains(num))
+        .count();
+    if num_overlap == 0 {
+        return 0;
+    }
+
+    let base: u32 = 2;
+    let points = base.pow((num_overlap as u32) - 1);
+    debug!("card {} points {}", card.id, points);
+
+    points
+}
+
+fn all_card_points(cards: &[Card]) -> u32 {
+    cards.iter()
+        .fold(0, |acc, card| acc + card_points(card) * card.instances)
+}
+
+fn generate_copies(cards: &mut [Card]) {
+    for i in (Range { start: 0, end: cards.len() }) {
+        let card = cards.get(i).unwrap();
+        let num_overlap = card.winning_numbers.iter()
+            .filter(|num| card.numbers.contains(num))
+            .count();
+
+        for _ in (Range { start: 0, end: card.instances }) {
+            for j in (Range { start: i + 1, end: (i + 1) + num_overlap }) {
+                if let Some(card) = cards.get_mut(j) {
+                    card.instances += 1;
+                }
+            }
+        }
+    }
+}
+
+// ---
+// Day
+// ---
+
+pub struct Day4;
+
+impl Day for Day4 {
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_all(lines(parse_card), input).expect("Valid cards")
+    }
+
+    fn part_one(cards: &Self::Input<'_>) -> impl Display {
+        all_card_points(cards)
+    }
+
+    fn part_two(cards: &Self::Input<'_>) -> impl Display {
+        let mut cards = cards.clone();
+        generate_copies(&mut cards);
+        cards.iter().fold(0, |acc, card| acc + card.instances)
+    }
+}
+
+advent::examples!(Day4, 2023, 4;
+    part_one: test_input => 13;
+    part_two: test_input => 30;
+);
@@ -0,0 +1,27 @@
+use log::info;
+
+use advent::InputSnake;
+use advent::day::{current_input_dir, Day};
+
+use aoc2023_day4::Day4;
+
+fn main() {
+    env_logger::init_from_env(env_logger::Env::default()
+        .filter_or(env_logger::DEFAULT_FILTER_ENV, "info"));
+
+    let inputs = current_input_dir(2023, 4);
+    let test_input = InputSnake::new(inputs.join("test_input")).no_snake();
+    let test_input = Day4::parse(&test_input);
+    let input = InputSnake::new(inputs.join("input")).no_snake();
+    let input = Day4::parse(&input);
+
+    info!("Part One Test");
+    info!("{}", Day4::part_one(&test_input));
+    info!("Part One");
+    info!("{}", Day4::part_one(&input));
+
+    info!("Part Two Test");
+    info!("{}", Day4::part_two(&test_input));
+    info!("Part Two");
+    info!("{}", Day4::part_two(&input));
+}
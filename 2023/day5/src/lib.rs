@@ -1,20 +1,14 @@
-use log::{debug, info, warn, error};
-use itertools::Itertools;
-use env_logger;
-use nom::bytes::complete::tag;
-use nom::character::complete::{space1, newline};
-use nom::multi::{separated_list0, separated_list1, many0, fold_many1};
-use nom::sequence::{terminated, preceded, pair, separated_pair};
-use regex::CaptureMatches;
-use once_cell::sync::Lazy;
-
-use std::boxed;
-use std::collections::{HashMap, HashSet};
-use std::fmt::Debug;
-use std::ops::Range;
-
-use advent::{InputSnake, FromRegex};
-use nom::{self, character, IResult, Parser};
+use log::debug;
+use nom::character::complete::{line_ending, space1};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{preceded, separated_pair, tuple};
+
+use std::collections::HashSet;
+use std::fmt::{Debug, Display};
+
+use advent::day::Day;
+use advent::parse::{labelled, labelled_section, lines, parse_all, unsigned, unsigned_list};
+use nom::{IResult, Parser};
 
 #[derive(Debug, Clone)]
 struct AlmanacMaps {
@@ -29,11 +23,21 @@ struct AlmanacMap {
 }
 
 #[derive(Debug)]
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<u64>,
     almanac_maps: Vec<AlmanacMaps>,
 }
 
+impl Almanac {
+    fn closest_location(&self) -> u64 {
+        self.seeds.iter()
+            .map(|&seed| self.almanac_maps.iter()
+                .fold(seed, |acc, maps| maps.get(acc)))
+            .min()
+            .unwrap()
+    }
+}
+
 impl AlmanacMaps {
     pub fn get(&self, source: u64) -> u64 {
         for map in self.maps.iter() {
@@ -42,7 +46,7 @@ impl AlmanacMaps {
             }
         }
 
-        return source;
+        source
     }
 }
 
@@ -61,56 +65,55 @@ impl AlmanacMap {
     }
 }
 
-const ALMANAC_MAP_NAMES: [&'static str; 7] = [
-    "seed-to-soil map:",
-    "soil-to-fertilizer map:",
-    "fertilizer-to-water map:",
-    "water-to-light map:",
-    "light-to-temperature map:",
-    "temperature-to-humidity map:",
-    "humidity-to-location map:",
+const ALMANAC_MAP_NAMES: [&str; 7] = [
+    "seed-to-soil map",
+    "soil-to-fertilizer map",
+    "fertilizer-to-water map",
+    "water-to-light map",
+    "light-to-temperature map",
+    "temperature-to-humidity map",
+    "humidity-to-location map",
 ];
 
-/// ------
-/// Part 1
-/// ------
-
-fn parse_almanac(input: &str) -> IResult<&str, Almanac> {
-    // seeds: 79 14 55 13
-    let (input, seeds) = preceded(
-        tag("seeds: "),
-        separated_list1(
-            space1,
-            nom::character::complete::u64
-        )
-    )(input)?;
+// 50 98 2
+fn parse_almanac_map(input: &str) -> IResult<&str, AlmanacMap> {
+    tuple((unsigned, preceded(space1, unsigned), preceded(space1, unsigned)))
+        .map(|(destination_range_start, source_range_start, range_len)| AlmanacMap {
+            destination_range_start,
+            source_range_start,
+            range_len,
+        })
+        .parse(input)
+}
 
+/// Parse each map section after a blank line, in the order of `ALMANAC_MAP_NAMES`.
+fn parse_almanac_maps(input: &str) -> IResult<&str, Vec<AlmanacMaps>> {
     let mut map_input = input;
     let mut almanac_maps: Vec<AlmanacMaps> = Vec::new();
     for almanac_map_name in ALMANAC_MAP_NAMES {
-        let (input, _) = many0(newline)(map_input)?;
-        let (input, _) = pair(
-            tag(almanac_map_name),
-            newline
-        )(input)?;
-        let (input, maps) = separated_list1(
-            newline,
-            separated_list1(
-                space1,
-                nom::character::complete::u64,
-            ).map(|values| AlmanacMap {
-                destination_range_start: *values.get(0).unwrap(),
-                source_range_start: *values.get(1).unwrap(),
-                range_len: *values.get(2).unwrap(),
-            })
-        )(input)?;
+        let (input, maps) = preceded(
+            many1(line_ending),
+            labelled_section(almanac_map_name, lines(parse_almanac_map)),
+        )(map_input)?;
 
         map_input = input;
         almanac_maps.push(AlmanacMaps { maps });
     }
 
+    Ok((map_input, almanac_maps))
+}
+
+// ------
+// Part 1
+// ------
+
+fn parse_almanac(input: &str) -> IResult<&str, Almanac> {
+    // seeds: 79 14 55 13
+    let (input, seeds) = labelled("seeds", unsigned_list::<u64>)(input)?;
+    let (input, almanac_maps) = parse_almanac_maps(input)?;
+
     Ok((
-        map_input,
+        input,
         Almanac {
             seeds,
             almanac_maps,
@@ -118,38 +121,9 @@ fn parse_almanac(input: &str) -> IResult<&str, Almanac> {
     ))
 }
 
-fn part_one_test() {
-    let input = InputSnake::new("test_input");
-    let no_snake = input.no_snake();
-    let (_, almanac) = parse_almanac(&no_snake).unwrap();
-    // dbg!(almanac);
-    
-    let closest_location = almanac.seeds.into_iter()
-        .map(|seed| almanac.almanac_maps.iter()
-            .fold(seed, |acc, maps| maps.get(acc)))
-        .min()
-        .unwrap();
-
-    info!("{:?}", closest_location);
-}
-
-fn part_one() {
-    let input = InputSnake::new("input");
-    let no_snake = input.no_snake();
-    let (_, almanac) = parse_almanac(&no_snake).unwrap();
-    
-    let closest_location = almanac.seeds.into_iter()
-        .map(|seed| almanac.almanac_maps.iter()
-            .fold(seed, |acc, maps| maps.get(acc)))
-        .min()
-        .unwrap();
-
-    info!("{:?}", closest_location);
-}
-
-/// ------
-/// Part 2
-/// ------
+// ------
+// Part 2
+// ------
 
 #[derive(Debug, Clone, Copy)]
 struct Seeds {
@@ -158,17 +132,20 @@ struct Seeds {
 }
 
 #[derive(Debug)]
-struct AlmanacTwo {
+pub struct AlmanacTwo {
     seeds: Vec<Seeds>,
     almanac_maps: Vec<AlmanacMaps>,
 }
 
+// only used by the brute force left commented out in `part_two`
+#[allow(dead_code)]
 impl Seeds {
     pub fn seeds(&self) -> std::ops::Range<u64> {
         self.range_start..(self.range_start + self.range_len)
     }
 }
 
+#[allow(dead_code)]
 impl AlmanacTwo {
     pub fn all_seeds(&self) -> Box<dyn Iterator<Item=u64>> {
         Box::new(self.seeds.clone().into_iter()
@@ -178,47 +155,21 @@ impl AlmanacTwo {
 
 fn parse_almanac_two(input: &str) -> IResult<&str, AlmanacTwo> {
     // seeds: 79 14 55 13
-    let (input, seeds) = preceded(
-        tag("seeds: "),
+    let (input, seeds) = labelled(
+        "seeds",
         separated_list1(
             space1,
-            separated_pair(
-                nom::character::complete::u64,
-                space1,
-                nom::character::complete::u64
-            ).map(|(range_start, range_len)| Seeds {
-                range_start,
-                range_len
-            })
+            separated_pair(unsigned, space1, unsigned)
+                .map(|(range_start, range_len)| Seeds {
+                    range_start,
+                    range_len
+                })
         )
     )(input)?;
-
-    let mut map_input = input;
-    let mut almanac_maps: Vec<AlmanacMaps> = Vec::new();
-    for almanac_map_name in ALMANAC_MAP_NAMES {
-        let (input, _) = many0(newline)(map_input)?;
-        let (input, _) = pair(
-            tag(almanac_map_name),
-            newline
-        )(input)?;
-        let (input, maps) = separated_list1(
-            newline,
-            separated_list1(
-                space1,
-                nom::character::complete::u64,
-            ).map(|values| AlmanacMap {
-                destination_range_start: *values.get(0).unwrap(),
-                source_range_start: *values.get(1).unwrap(),
-                range_len: *values.get(2).unwrap(),
-            })
-        )(input)?;
-
-        map_input = input;
-        almanac_maps.push(AlmanacMaps { maps });
-    }
+    let (input, almanac_maps) = parse_almanac_maps(input)?;
 
     Ok((
-        map_input,
+        input,
         AlmanacTwo {
             seeds,
             almanac_maps,
@@ -240,9 +191,9 @@ impl SeedMap {
     }
 }
 
-fn solve(almanac: AlmanacTwo) -> u64 {
+fn solve(almanac: &AlmanacTwo) -> u64 {
     // use inclusive ranges
-    let mut seeds: HashSet<(u64, u64)> = almanac.seeds.into_iter()
+    let mut seeds: HashSet<(u64, u64)> = almanac.seeds.iter()
         .map(|seed| (seed.range_start, seed.range_start + seed.range_len - 1))
         .collect();
     // dbg!(seeds);
@@ -259,13 +210,13 @@ fn solve(almanac: AlmanacTwo) -> u64 {
     // dbg!(almanac_maps);
 
     // iterate over the almanc maps, in order, updating the ranges of seeds as they are applied
-    for (i, almanac_map) in almanac_maps.iter().enumerate() {
+    for almanac_map in almanac_maps.iter() {
         let mut mapped_seeds = HashSet::new();
         'seedloop: while !seeds.is_empty() {
             let seed = *seeds.iter().next().unwrap();
             seeds.take(&seed);
 
-            'maploop: for map in almanac_map.iter() {
+            for map in almanac_map.iter() {
                 // the mapped seeds depend on an overlap in seed and seed map ranges,
                 // of which there are several cases:
                 //
@@ -351,62 +302,45 @@ fn solve(almanac: AlmanacTwo) -> u64 {
     }
 
     // return the lowest "seed" (which is now a location)
-    info!("{:?}", seeds);
+    debug!("{:?}", seeds);
     seeds.iter()
         .map(|&(start, _end)| start)
         .min()
         .unwrap()
 }
 
+// ---
+// Day
+// ---
 
-fn part_two_test() {
-    let input = InputSnake::new("test_input");
-    let no_snake = input.no_snake();
-    let (_, almanac) = parse_almanac_two(&no_snake).unwrap();
-    
-    // let closest_location = almanac.all_seeds().into_iter()
-    //     .map(|seed| almanac.almanac_maps.iter()
-    //         .fold(seed, |acc, maps| maps.get(acc)))
-    //     .min()
-    //     .unwrap();
+pub struct Day5;
 
-    let closest_location = solve(almanac);
+impl Day for Day5 {
+    type Input<'a> = (Almanac, AlmanacTwo);
 
-    info!("{:?}", closest_location);
-}
+    fn parse(input: &str) -> Self::Input<'_> {
+        let almanac = parse_all(parse_almanac, input).expect("Valid almanac");
+        let almanac_two = parse_all(parse_almanac_two, input).expect("Valid almanac");
+        (almanac, almanac_two)
+    }
 
-fn part_two() {
-    let input = InputSnake::new("input");
-    let no_snake = input.no_snake();
-    let (_, almanac) = parse_almanac_two(&no_snake).unwrap();
-
-    // let all_seeds = almanac.all_seeds().into_iter().count();
-    // info!("all_seeds: {}", all_seeds);
-    
-    // let closest_location = almanac.all_seeds().into_iter()
-    //     .enumerate()
-    //     .inspect(|(i, _seed)| if i % 10000 == 0 { info!("{}%", ((*i as f32) / 1638141121.0) * 100.0) })
-    //     .map(|(_i, seed)| almanac.almanac_maps.iter()
-    //         .fold(seed, |acc, maps| maps.get(acc)))
-    //     .min()
-    //     .unwrap();
-
-    let closest_location = solve(almanac);
-
-    info!("{:?}", closest_location);
-}
+    fn part_one((almanac, _): &Self::Input<'_>) -> impl Display {
+        almanac.closest_location()
+    }
 
-fn main() {
-    env_logger::init_from_env(env_logger::Env::default()
-        .filter_or(env_logger::DEFAULT_FILTER_ENV, "info"));
-
-    // info!("Part One Test");
-    // part_one_test();
-    // info!("Part One");
-    // part_one();
- 
-    info!("Part Two Test");
-    part_two_test();
-    info!("Part Two");
-    part_two();
+    fn part_two((_, almanac_two): &Self::Input<'_>) -> impl Display {
+        // brute force over every seed, too slow for the real input:
+        //
+        // almanac_two.all_seeds()
+        //     .map(|seed| almanac_two.almanac_maps.iter()
+        //         .fold(seed, |acc, maps| maps.get(acc)))
+        //     .min()
+        //     .unwrap()
+        solve(almanac_two)
+    }
 }
+
+advent::examples!(Day5, 2023, 5;
+    part_one: test_input => 35;
+    part_two: test_input => 46;
+);
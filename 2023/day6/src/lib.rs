@@ -0,0 +1,105 @@
+use itertools::Itertools;
+use nom::IResult;
+use nom::character::complete::newline;
+
+use std::fmt::{Debug, Display};
+use std::iter::zip;
+
+use advent::day::Day;
+use advent::parse::{labelled, parse_all, unsigned_list};
+
+// Time:      7  15   30
+// Distance:  9  40  200
+#[derive(Debug)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+fn margin_of_error(races: &[Race]) -> u64 {
+    races.iter()
+        .map(|race| (0..race.time)
+            .map(|charge_time| charge_time * (race.time - charge_time))
+            .filter(|&distance| distance > race.distance)
+            .count() as u64)
+        .product()
+}
+
+// ------
+// Part 1
+// ------
+
+fn parse_races(input: &str) -> IResult<&str, Vec<Race>> {
+    let (input, times) = labelled("Time", unsigned_list::<u64>)(input)?;
+    let (input, _) = newline(input)?;
+    let (input, distances) = labelled("Distance", unsigned_list::<u64>)(input)?;
+
+    debug_assert_eq!(times.len(), distances.len());
+
+    let races = zip(times, distances)
+        .map(|(time, distance)| Race {
+            time,
+            distance,
+        })
+        .collect();
+
+    Ok((
+        input,
+        races
+    ))
+}
+
+// ------
+// Part 2
+// ------
+
+fn parse_races_two(input: &str) -> IResult<&str, Vec<Race>> {
+    let (input, times) = labelled("Time", unsigned_list::<u64>)(input)?;
+    let (input, _) = newline(input)?;
+    let (input, distances) = labelled("Distance", unsigned_list::<u64>)(input)?;
+
+    debug_assert_eq!(times.len(), distances.len());
+
+    let time = times.into_iter()
+        .map(|time| format!("{time}"))
+        .join("")
+        .parse::<u64>().expect("valid time");
+    let distance = distances.into_iter()
+        .map(|distance| format!("{distance}"))
+        .join("")
+        .parse::<u64>().expect("valid distance");
+
+    let races = vec!(Race { time, distance });
+
+    Ok((
+        input,
+        races
+    ))
+}
+
+// ---
+// Day
+// ---
+
+pub struct Day6;
+
+impl Day for Day6 {
+    type Input<'a> = (Vec<Race>, Vec<Race>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        (parse_all(parse_races, input).unwrap(), parse_all(parse_races_two, input).unwrap())
+    }
+
+    fn part_one((races, _): &Self::Input<'_>) -> impl Display {
+        margin_of_error(races)
+    }
+
+    fn part_two((_, races_two): &Self::Input<'_>) -> impl Display {
+        margin_of_error(races_two)
+    }
+}
+
+advent::examples!(Day6, 2023, 6;
+    part_one: test_input => 288;
+    part_two: test_input => 71503;
+);
@@ -0,0 +1,27 @@
+use log::info;
+
+use advent::InputSnake;
+use advent::day::{current_input_dir, Day};
+
+use aoc2023_day6::Day6;
+
+fn main() {
+    env_logger::init_from_env(env_logger::Env::default()
+        .filter_or(env_logger::DEFAULT_FILTER_ENV, "debug"));
+
+    let inputs = current_input_dir(2023, 6);
+    let test_input = InputSnake::new(inputs.join("test_input")).no_snake();
+    let test_input = Day6::parse(&test_input);
+    let input = InputSnake::new(inputs.join("input")).no_snake();
+    let input = Day6::parse(&input);
+
+    info!("Part One Test");
+    info!("{}", Day6::part_one(&test_input));
+    info!("Part One");
+    info!("{}", Day6::part_one(&input));
+
+    info!("Part Two Test");
+    info!("{}", Day6::part_two(&test_input));
+    info!("Part Two");
+    info!("{}", Day6::part_two(&input));
+}
@@ -0,0 +1,75 @@
+use log::debug;
+use itertools::Itertools;
+use nom::character::complete::{alphanumeric1, space1};
+use nom::sequence::separated_pair;
+use nom::IResult;
+
+use std::fmt::Display;
+
+use advent::day::Day;
+use advent::cards::{Hand, HandRanker};
+use advent::parse::{lines, parse_all, unsigned};
+
+const CARD_ORDER: &str = "23456789TJQKA";
+
+#[derive(Debug)]
+pub struct Bid<'a> {
+    cards: &'a str,
+    bid: u32,
+}
+
+fn parse_bid(input: &str) -> IResult<&str, Bid<'_>> {
+    let (input, (cards, bid)) = separated_pair(
+        alphanumeric1,
+        space1,
+        unsigned,
+    )(input)?;
+
+    Ok((
+        input,
+        Bid {
+            cards,
+            bid,
+        }
+    ))
+}
+
+fn total_winnings(bids: &[Bid], ranker: &HandRanker) -> u32 {
+    let hands: Vec<(Hand, u32)> = bids.iter()
+        .map(|bid| (ranker.hand(bid.cards), bid.bid))
+        .sorted()
+        .collect();
+
+    debug!("{:?}", hands.iter().map(|(hand, _)| (hand.cards(), hand.category())).collect_vec());
+
+    hands.iter()
+        .enumerate()
+        .fold(0, |acc, (i, (_, bid))| acc + (i as u32 + 1) * bid)
+}
+
+// ---
+// Day
+// ---
+
+pub struct Day7;
+
+impl Day for Day7 {
+    type Input<'a> = Vec<Bid<'a>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_all(lines(parse_bid), input).expect("Valid bids")
+    }
+
+    fn part_one(bids: &Self::Input<'_>) -> impl Display {
+        total_winnings(bids, &HandRanker::new(CARD_ORDER))
+    }
+
+    fn part_two(bids: &Self::Input<'_>) -> impl Display {
+        total_winnings(bids, &HandRanker::new(CARD_ORDER).with_wildcards("J"))
+    }
+}
+
+advent::examples!(Day7, 2023, 7;
+    part_one: test_input => 6440;
+    part_two: test_input => 5905;
+);
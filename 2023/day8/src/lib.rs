@@ -1,49 +1,40 @@
-use log::{debug, info, warn, error};
+use log::debug;
 use itertools::Itertools;
-use env_logger;
-use nom::character;
-use nom::character::complete::{alpha1, space1, newline, space0, alphanumeric1};
-use nom::multi::{many0, separated_list1, fold_many1};
-use regex::CaptureMatches;
-use once_cell::sync::Lazy;
-use nom::{bytes::complete::*, combinator::*, error::*, sequence::*, IResult, Parser};
+use nom::character::complete::{alphanumeric1, line_ending};
+use nom::multi::many1;
+use nom::sequence::separated_pair;
+use nom::IResult;
 
-use std::collections::{HashMap, HashSet, BTreeMap};
-use std::fmt::Debug;
-use std::mem;
-use std::rc::Rc;
+use std::collections::{HashSet, BTreeMap};
+use std::fmt::{Debug, Display};
 
-use advent::{InputSnake, FromRegex};
+use advent::day::Day;
+use advent::parse::{key_value, lines, parenthesized_pair, parse_all, ParseError};
+use advent::InputBuffer;
 
 #[derive(Debug)]
-struct Document<'a> {
+pub struct Document<'a> {
     instructions: &'a str,
     nodes: BTreeMap<&'a str, (&'a str, &'a str)>
 }
 
-fn parse_document(input: &str) -> IResult<&str, Document> {
-    let (input, instructions) = alphanumeric1(input)?;
-    let (input, _) = newline(input)?;
-
-    let (input, nodes) = fold_many1(
-        preceded(
-            newline,
-            separated_pair(
-                alphanumeric1,
-                tuple((space1, tag("="), space1)),
-                separated_pair(
-                    preceded(tag("("), alphanumeric1),
-                    tuple((tag(","), space1)),
-                    terminated(alphanumeric1, tag(")"))
-                )
-            )
-        ),
-        BTreeMap::new,
-        |mut acc, (parent, (left, right))| {
-            acc.insert(parent, (left, right));
-            acc
-        }
+impl <'a> Document<'a> {
+    /// Parse a document which borrows its node names from the buffer.
+    pub fn from_buffer(buffer: &'a InputBuffer) -> Result<Self, ParseError> {
+        buffer.parse(parse_document)
+    }
+}
+
+fn parse_document(input: &str) -> IResult<&str, Document<'_>> {
+    // AAA = (BBB, CCC)
+    let node = key_value(alphanumeric1, "=", parenthesized_pair(alphanumeric1));
+
+    let (input, (instructions, nodes)) = separated_pair(
+        alphanumeric1,
+        many1(line_ending),
+        lines(node),
     )(input)?;
+    let nodes = nodes.into_iter().collect();
 
     Ok((
         input,
@@ -54,9 +45,9 @@ fn parse_document(input: &str) -> IResult<&str, Document> {
     ))
 }
 
-/// ------
-/// Part 1
-/// ------
+// ------
+// Part 1
+// ------
 
 fn num_steps(document: &Document) -> u32 {
     let mut steps = 0;
@@ -78,40 +69,14 @@ fn num_steps(document: &Document) -> u32 {
     }
 }
 
-fn part_one_test() {
-    let input = InputSnake::new("test_input");
-    let no_snake = input.no_snake();
-    let (_, document) = parse_document(&no_snake).unwrap();
-    dbg!(&document);
-
-    let num_steps = num_steps(&document);
-    info!("{:?}", num_steps);
-}
-
-fn part_one_test_two() {
-    let input = InputSnake::new("test_input_2");
-    let no_snake = input.no_snake();
-    let (_, document) = parse_document(&no_snake).unwrap();
-
-    let num_steps = num_steps(&document);
-    info!("{:?}", num_steps);
-}
-
-fn part_one() {
-    let input = InputSnake::new("input");
-    let no_snake = input.no_snake();
-    let (_, document) = parse_document(&no_snake).unwrap();
-
-    let num_steps = num_steps(&document);
-    info!("{:?}", num_steps);
-}
-
-/// ------
-/// Part 2
-/// ------
+// ------
+// Part 2
+// ------
 
 type StepCycle<'a> = (&'a str, usize);
 
+// the cycle details are only logged, the step count is what's used
+#[allow(dead_code)]
 #[derive(Debug)]
 struct StepCycleData<'a> {
     step_cycle: StepCycle<'a>,
@@ -194,9 +159,9 @@ fn get_step_cycle_data<'a>(document: &'a Document, step_cycle: StepCycle<'a>) ->
 }
 
 fn num_ghost_steps(document: &Document) -> usize {
-    let mut locations: Vec<&str> = document.nodes.keys()
+    let locations: Vec<&str> = document.nodes.keys()
         .filter(|&k| k.ends_with("A"))
-        .map(|&k| k)
+        .copied()
         .collect();
 
     let step_cycle_datas = locations.iter()
@@ -220,7 +185,7 @@ fn num_ghost_steps(document: &Document) -> usize {
 
     debug!("step_cycle_instruction {:?}", num_steps_lcm);
 
-    return num_steps_lcm;
+    num_steps_lcm
 
     // too slow
     //
@@ -264,37 +229,29 @@ fn gcd_of_two_numbers(a: usize, b: usize) -> usize {
     gcd_of_two_numbers(b, a % b)
 }
 
-fn part_two_test() {
-    let input = InputSnake::new("test_input_3");
-    let no_snake = input.no_snake();
-    let (_, document) = parse_document(&no_snake).unwrap();
+// ---
+// Day
+// ---
 
-    let num_steps = num_ghost_steps(&document);
-    info!("{:?}", num_steps);
-}
+pub struct Day8;
 
-fn part_two() {
-    let input = InputSnake::new("input");
-    let no_snake = input.no_snake();
-    let (_, document) = parse_document(&no_snake).unwrap();
+impl Day for Day8 {
+    type Input<'a> = Document<'a>;
 
-    let num_steps = num_ghost_steps(&document);
-    info!("{:?}", num_steps);
-}
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_all(parse_document, input).expect("Valid document")
+    }
+
+    fn part_one(document: &Self::Input<'_>) -> impl Display {
+        num_steps(document)
+    }
 
-fn main() {
-    env_logger::init_from_env(env_logger::Env::default()
-        .filter_or(env_logger::DEFAULT_FILTER_ENV, "debug"));
-
-    info!("Part One Test");
-    part_one_test();
-    info!("Part One Test Two");
-    part_one_test_two();
-    info!("Part One");
-    part_one();
- 
-    info!("Part Two Test");
-    part_two_test();
-    info!("Part Two");
-    part_two();
+    fn part_two(document: &Self::Input<'_>) -> impl Display {
+        num_ghost_steps(document)
+    }
 }
+
+advent::examples!(Day8, 2023, 8;
+    part_one: test_input => 2, test_input_2 => 6;
+    part_two: test_input_3 => 6;
+);
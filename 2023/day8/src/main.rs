@@ -0,0 +1,33 @@
+use log::info;
+
+use advent::InputSnake;
+use advent::day::{current_input_dir, Day};
+
+use aoc2023_day8::{Day8, Document};
+
+fn main() {
+    env_logger::init_from_env(env_logger::Env::default()
+        .filter_or(env_logger::DEFAULT_FILTER_ENV, "debug"));
+
+    let inputs = current_input_dir(2023, 8);
+    let test_input = InputSnake::new(inputs.join("test_input")).buffer();
+    let test_input = Document::from_buffer(&test_input).expect("Valid document");
+    let test_input_2 = InputSnake::new(inputs.join("test_input_2")).buffer();
+    let test_input_2 = Document::from_buffer(&test_input_2).expect("Valid document");
+    let test_input_3 = InputSnake::new(inputs.join("test_input_3")).buffer();
+    let test_input_3 = Document::from_buffer(&test_input_3).expect("Valid document");
+    let input = InputSnake::new(inputs.join("input")).buffer();
+    let input = Document::from_buffer(&input).expect("Valid document");
+
+    info!("Part One Test");
+    info!("{}", Day8::part_one(&test_input));
+    info!("Part One Test Two");
+    info!("{}", Day8::part_one(&test_input_2));
+    info!("Part One");
+    info!("{}", Day8::part_one(&input));
+
+    info!("Part Two Test");
+    info!("{}", Day8::part_two(&test_input_3));
+    info!("Part Two");
+    info!("{}", Day8::part_two(&input));
+}
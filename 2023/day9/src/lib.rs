@@ -0,0 +1,58 @@
+use log::debug;
+use itertools::Itertools;
+
+use std::fmt::Display;
+
+use advent::day::Day;
+use advent::parse::{lines, parse_all, signed_list};
+
+fn extrapolate(history: &[i32]) -> i32 {
+    if history.iter().all(|&h| h == 0) {
+        return 0;
+    }
+
+    let last = history.last().unwrap();
+    let diffs = history.iter()
+        .tuple_windows()
+        .map(|(a, b)| b - a)
+        .collect_vec();
+
+    debug!("{:?} {:?}", last, diffs);
+    last + extrapolate(&diffs)
+}
+
+fn sum_extrapolated(histories: &[Vec<i32>]) -> i32 {
+    histories.iter()
+        .map(|history| extrapolate(history))
+        .sum()
+}
+
+// ---
+// Day
+// ---
+
+pub struct Day9;
+
+impl Day for Day9 {
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_all(lines(signed_list), input).expect("Valid histories")
+    }
+
+    fn part_one(histories: &Self::Input<'_>) -> impl Display {
+        sum_extrapolated(histories)
+    }
+
+    fn part_two(histories: &Self::Input<'_>) -> impl Display {
+        let reversed = histories.iter()
+            .map(|history| history.iter().rev().copied().collect_vec())
+            .collect_vec();
+        sum_extrapolated(&reversed)
+    }
+}
+
+advent::examples!(Day9, 2023, 9;
+    part_one: test_input => 114;
+    part_two: test_input => 2;
+);
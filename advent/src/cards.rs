@@ -0,0 +1,208 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// The category of a hand, ordered from weakest to strongest.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Category {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// Classifies hands using a card order and an optional set of wildcards.
+#[derive(Debug, Clone)]
+pub struct HandRanker {
+    order: Vec<char>,
+    wildcards: HashSet<char>,
+}
+
+/// A classified hand which orders by category and then card by card.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Hand {
+    category: Category,
+    values: Vec<usize>,
+    cards: String,
+}
+
+impl Category {
+    /// Return the category for card counts sorted from largest to smallest.
+    fn from_counts(counts: &[usize]) -> Self {
+        match counts {
+            [5, ..] => Category::FiveOfAKind,
+            [4, ..] => Category::FourOfAKind,
+            [3, 2, ..] => Category::FullHouse,
+            [3, ..] => Category::ThreeOfAKind,
+            [2, 2, ..] => Category::TwoPair,
+            [2, ..] => Category::OnePair,
+            _ => Category::HighCard,
+        }
+    }
+}
+
+impl HandRanker {
+    /// Create a ranker from the card characters ordered from weakest to strongest.
+    pub fn new(order: &str) -> Self {
+        HandRanker {
+            order: order.chars().collect(),
+            wildcards: HashSet::new(),
+        }
+    }
+
+    /// Treat the given cards as wildcards, which are worth less than every other card.
+    pub fn with_wildcards(mut self, wildcards: &str) -> Self {
+        self.wildcards = wildcards.chars().collect();
+        self
+    }
+
+    /// Return whether the card is a wildcard.
+    pub fn is_wildcard(&self, card: char) -> bool {
+        self.wildcards.contains(&card)
+    }
+
+    /// Return the tie-breaking value of a card, with wildcards always worth 0.
+    pub fn card_value(&self, card: char) -> usize {
+        if self.is_wildcard(card) {
+            return 0;
+        }
+
+        self.order.iter()
+            .position(|&c| c == card)
+            .map(|i| i + 1)
+            .unwrap_or_else(|| panic!("Unhandled card character: {}", card))
+    }
+
+    /// Return the best category of the cards, with every wildcard joining the largest group.
+    pub fn category(&self, cards: &str) -> Category {
+        let mut wildcards = 0;
+        let mut count_by_card: HashMap<char, usize> = HashMap::new();
+        for card in cards.chars() {
+            if self.is_wildcard(card) {
+                wildcards += 1;
+            } else {
+                *count_by_card.entry(card).or_default() += 1;
+            }
+        }
+
+        let mut counts: Vec<usize> = count_by_card.into_values().collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        match counts.first_mut() {
+            Some(largest) => *largest += wildcards,
+            None => counts.push(wildcards),
+        }
+
+        Category::from_counts(&counts)
+    }
+
+    /// Classify the cards into a hand.
+    pub fn hand(&self, cards: &str) -> Hand {
+        Hand {
+            category: self.category(cards),
+            values: cards.chars().map(|c| self.card_value(c)).collect(),
+            cards: cards.to_string(),
+        }
+    }
+}
+
+impl Hand {
+    /// Return the category of the hand.
+    pub fn category(&self) -> Category {
+        self.category
+    }
+
+    /// Return the cards as they were dealt.
+    pub fn cards(&self) -> &str {
+        &self.cards
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDER: &str = "23456789TJQKA";
+    const JOKER_ORDER: &str = "J23456789TQKA";
+
+    #[test]
+    fn wildcards_join_the_largest_group() {
+        let ranker = HandRanker::new(JOKER_ORDER).with_wildcards("J");
+        assert_eq!(ranker.category("JJJJJ"), Category::FiveOfAKind);
+        assert_eq!(ranker.category("KTJJT"), Category::FourOfAKind);
+        assert_eq!(ranker.category("QJJQ2"), Category::FourOfAKind);
+        assert_eq!(ranker.category("2345J"), Category::OnePair);
+
+        let ranker = HandRanker::new(ORDER);
+        assert_eq!(ranker.category("JJJJJ"), Category::FiveOfAKind);
+        assert_eq!(ranker.category("KTJJT"), Category::TwoPair);
+        assert_eq!(ranker.category("QJJQ2"), Category::TwoPair);
+    }
+
+    #[test]
+    fn jacks_are_worth_least_as_wildcards() {
+        let ranker = HandRanker::new(ORDER);
+        assert!(!ranker.is_wildcard('J'));
+        assert_eq!(ranker.card_value('J'), 10);
+        assert!(ranker.card_value('J') > ranker.card_value('T'));
+
+        let ranker = HandRanker::new(JOKER_ORDER).with_wildcards("J");
+        assert!(ranker.is_wildcard('J'));
+        assert_eq!(ranker.card_value('J'), 0);
+        assert!(ranker.card_value('J') < ranker.card_value('2'));
+    }
+
+    #[test]
+    fn ties_are_broken_card_by_card() {
+        let ranker = HandRanker::new(ORDER);
+        assert!(ranker.hand("33332") > ranker.hand("2AAAA"));
+        assert!(ranker.hand("KK677") > ranker.hand("KTJJT"));
+        assert!(ranker.hand("T55J5") < ranker.hand("QQQJA"));
+
+        let ranker = HandRanker::new(JOKER_ORDER).with_wildcards("J");
+        assert!(ranker.hand("JKKK2") < ranker.hand("QQQQ2"));
+        assert_eq!(ranker.hand("KTJJT").cmp(&ranker.hand("KTJJT")), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn hands_order_by_category_first() {
+        let ranker = HandRanker::new(ORDER);
+        let mut hands: Vec<Hand> = ["AAAAA", "23456", "AAKQJ", "2222A", "AAKKQ", "22333", "AAAKQ"].iter()
+            .map(|cards| ranker.hand(cards))
+            .collect();
+        hands.sort();
+
+        let categories: Vec<Category> = hands.iter().map(Hand::category).collect();
+        assert_eq!(categories, vec![
+            Category::HighCard,
+            Category::OnePair,
+            Category::TwoPair,
+            Category::ThreeOfAKind,
+            Category::FullHouse,
+            Category::FourOfAKind,
+            Category::FiveOfAKind,
+        ]);
+        assert!(categories.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(hands[0].cards(), "23456");
+        assert_eq!(hands[6].to_string(), "AAAAA");
+    }
+
+    #[test]
+    fn example_winnings() {
+        let bids = [("32T3K", 765), ("T55J5", 684), ("KK677", 28), ("KTJJT", 220), ("QQQJA", 483)];
+        let winnings = |ranker: HandRanker| {
+            let mut hands: Vec<(Hand, u64)> = bids.iter().map(|&(cards, bid)| (ranker.hand(cards), bid)).collect();
+            hands.sort();
+            hands.iter().enumerate().map(|(i, (_, bid))| (i as u64 + 1) * bid).sum::<u64>()
+        };
+        assert_eq!(winnings(HandRanker::new(ORDER)), 6440);
+        assert_eq!(winnings(HandRanker::new(JOKER_ORDER).with_wildcards("J")), 5905);
+    }
+}
@@ -0,0 +1,348 @@
+//! Walking over a map which is the unfolded net of a cube.
+//!
+//! Each face gets an orientation in 3D by folding the net along the edges between its tiles, so
+//! the seams between faces which are not next to each other in the net are found for any net.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::grid::{Coord, Grid};
+use crate::map::Direction;
+use crate::point::{Heading, Point};
+
+type Vector = [i64; 3];
+
+const CARDINAL_DIRECTIONS: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+/// The six faces of a cube net drawn on a grid, with spaces or holes around them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cube {
+    side: i64,
+    faces: Vec<Face>,
+}
+
+/// A square face of the net and where it is on the folded cube.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Face {
+    /// The top left cell of the face on the grid.
+    origin: Coord,
+    /// The direction on the cube in which the columns of the face grow.
+    right: Vector,
+    /// The direction on the cube in which the rows of the face grow.
+    down: Vector,
+    /// The outward direction of the face.
+    normal: Vector,
+}
+
+/// The reason a grid could not be folded into a cube.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CubeError {
+    /// The number of cells is not six equal squares.
+    NotSixSquares(usize),
+    /// The faces are not all on a grid of squares of the same side.
+    UnalignedFaces,
+    /// The faces do not fold into a cube, such as when two of them overlap.
+    NotANet,
+}
+
+impl Cube {
+    /// Find the faces of the net, where every cell other than a space is part of a face.
+    pub fn from_grid(grid: &Grid<char>) -> Result<Self, CubeError> {
+        let cells: Vec<Coord> = grid.occupied()
+            .filter(|(_, &c)| c != ' ')
+            .map(|(coord, _)| coord)
+            .collect();
+        let side = (1..).find(|s| 6 * s * s >= cells.len()).unwrap_or(1) as i64;
+        if cells.is_empty() || 6 * side * side != cells.len() as i64 {
+            return Err(CubeError::NotSixSquares(cells.len()));
+        }
+
+        let min_row = cells.iter().map(|c| c.0).min().unwrap_or(0);
+        let min_col = cells.iter().map(|c| c.1).min().unwrap_or(0);
+        let tile = |coord: Coord| ((coord.0 - min_row).div_euclid(side), (coord.1 - min_col).div_euclid(side));
+        let mut tiles: Vec<(i64, i64)> = cells.iter().map(|&c| tile(c)).collect();
+        tiles.sort_unstable();
+        tiles.dedup();
+        if tiles.len() != 6 {
+            return Err(CubeError::UnalignedFaces);
+        }
+
+        let origin = |t: (i64, i64)| (min_row + t.0 * side, min_col + t.1 * side);
+        let mut faces: Vec<Option<Face>> = vec![None; 6];
+        faces[0] = Some(Face { origin: origin(tiles[0]), right: [1, 0, 0], down: [0, 1, 0], normal: [0, 0, 1] });
+        let mut queue = VecDeque::from([0]);
+        while let Some(i) = queue.pop_front() {
+            let face = faces[i].expect("Queued faces are folded");
+            for &direction in &CARDINAL_DIRECTIONS {
+                let unit = direction.unit();
+                let neighbour = (tiles[i].0 + unit.row(), tiles[i].1 + unit.col());
+                if let Some(j) = tiles.iter().position(|&t| t == neighbour) {
+                    if faces[j].is_none() {
+                        faces[j] = Some(face.fold(direction, origin(neighbour)));
+                        queue.push_back(j);
+                    }
+                }
+            }
+        }
+
+        let faces: Vec<Face> = faces.into_iter()
+            .collect::<Option<_>>()
+            .ok_or(CubeError::NotANet)?;
+        let mut normals: Vec<Vector> = faces.iter().map(|f| f.normal).collect();
+        normals.sort_unstable();
+        normals.dedup();
+        if normals.len() != 6 {
+            return Err(CubeError::NotANet);
+        }
+        Ok(Cube { side, faces })
+    }
+
+    /// Return the length of a side of each face.
+    pub fn side(&self) -> i64 {
+        self.side
+    }
+
+    /// Return the index of the face containing the coordinate, in row-major order of the net.
+    pub fn face(&self, coord: Coord) -> Option<usize> {
+        self.faces.iter().position(|f| f.contains(coord, self.side))
+    }
+
+    /// Return the top left coordinate of each face.
+    pub fn origins(&self) -> Vec<Coord> {
+        self.faces.iter().map(|f| f.origin).collect()
+    }
+
+    /// Return the face on the other side of an edge of a face, and which of its edges it is.
+    pub fn seam(&self, face: usize, edge: Direction) -> (usize, Direction) {
+        let from = &self.faces[face];
+        let normal = from.edge(edge);
+        let to = self.faces.iter()
+            .position(|f| f.normal == normal)
+            .expect("Every direction on the cube has a face");
+        let to_edge = CARDINAL_DIRECTIONS.iter()
+            .copied()
+            .find(|&e| self.faces[to].edge(e) == from.normal)
+            .expect("Neighbouring faces share an edge");
+        (to, to_edge)
+    }
+
+    /// Return the coordinate one step in the direction and the direction faced afterwards, which
+    /// changes when the step crosses onto a face which is not next to it in the net.
+    ///
+    /// Panics if the coordinate is not on a face.
+    pub fn next_location(&self, coord: Coord, direction: Direction) -> (Coord, Direction) {
+        let next = direction.step(Point(coord), 1).0;
+        let index = self.face(coord).expect("Coordinate is on a face");
+        let face = &self.faces[index];
+        if face.contains(next, self.side) {
+            return (next, direction);
+        }
+
+        let (to, to_edge) = self.seam(index, direction);
+        let position = add(sub(face.position(coord, self.side), face.normal), face.edge(direction));
+        (self.faces[to].cell(position, self.side), to_edge.opposite())
+    }
+}
+
+impl Face {
+    /// Return the face next to this one in the net, folded over the edge in the direction.
+    fn fold(&self, direction: Direction, origin: Coord) -> Face {
+        let (right, down, normal) = match direction {
+            Direction::East => (neg(self.normal), self.down, self.right),
+            Direction::West => (self.normal, self.down, neg(self.right)),
+            Direction::South => (self.right, neg(self.normal), self.down),
+            Direction::North => (self.right, self.normal, neg(self.down)),
+        };
+        Face { origin, right, down, normal }
+    }
+
+    fn contains(&self, coord: Coord, side: i64) -> bool {
+        (self.origin.0..self.origin.0 + side).contains(&coord.0)
+            && (self.origin.1..self.origin.1 + side).contains(&coord.1)
+    }
+
+    /// Return the outward direction of the edge of the face in the direction.
+    fn edge(&self, direction: Direction) -> Vector {
+        match direction {
+            Direction::East => self.right,
+            Direction::South => self.down,
+            Direction::West => neg(self.right),
+            Direction::North => neg(self.down),
+        }
+    }
+
+    /// Return the centre of a cell of the face on a cube from -side to side, which keeps the
+    /// centres of cells on whole numbers.
+    fn position(&self, coord: Coord, side: i64) -> Vector {
+        let row = 2 * (coord.0 - self.origin.0) - (side - 1);
+        let col = 2 * (coord.1 - self.origin.1) - (side - 1);
+        add(add(scale(self.normal, side), scale(self.right, col)), scale(self.down, row))
+    }
+
+    /// Return the cell of the face with the centre from `position`.
+    fn cell(&self, position: Vector, side: i64) -> Coord {
+        let row = (dot(position, self.down) + side - 1) / 2;
+        let col = (dot(position, self.right) + side - 1) / 2;
+        (self.origin.0 + row, self.origin.1 + col)
+    }
+}
+
+fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: Vector, b: Vector) -> Vector {
+    add(a, neg(b))
+}
+
+fn neg(a: Vector) -> Vector {
+    scale(a, -1)
+}
+
+fn scale(a: Vector, value: i64) -> Vector {
+    [a[0] * value, a[1] * value, a[2] * value]
+}
+
+fn dot(a: Vector, b: Vector) -> i64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+impl fmt::Display for CubeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CubeError::NotSixSquares(cells) => write!(f, "{} cells are not six equal squares", cells),
+            CubeError::UnalignedFaces => write!(f, "the faces are not aligned on squares"),
+            CubeError::NotANet => write!(f, "the faces do not fold into a cube"),
+        }
+    }
+}
+
+impl std::error::Error for CubeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InputSnake;
+
+    const EXAMPLE: &str = r#"
+        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+"#;
+
+    /// The eleven nets of a cube as tiles, where `#` is a face.
+    const NETS: [&str; 11] = [
+        "#...\n####\n#...",
+        "#...\n####\n.#..",
+        "#...\n####\n..#.",
+        "#...\n####\n...#",
+        ".#..\n####\n.#..",
+        ".#..\n####\n..#.",
+        "##..\n.###\n.#..",
+        "##..\n.###\n..#.",
+        "##..\n.###\n...#",
+        "##..\n.##.\n..##",
+        "###..\n..###",
+    ];
+
+    /// Draw the tiles as faces of open cells with the side.
+    fn net(tiles: &str, side: i64) -> Grid<char> {
+        let mut grid = Grid::new();
+        for (row, line) in tiles.lines().enumerate() {
+            for (col, tile) in line.chars().enumerate() {
+                for i in 0..side {
+                    for j in 0..side {
+                        let c = if tile == '#' { '.' } else { ' ' };
+                        grid.add_entry((row as i64 * side + i, col as i64 * side + j), c);
+                    }
+                }
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn example_password() {
+        let grid = InputSnake::from_text(EXAMPLE).grid_snake();
+        let cube = Cube::from_grid(&grid).unwrap();
+        assert_eq!(cube.side(), 4);
+        assert_eq!(cube.origins()[0], (0, 8));
+
+        let mut coord = (0, 8);
+        let mut direction = Direction::East;
+        let path = "10R5L5R10L4R5L5";
+        let steps = path.split(['L', 'R']).map(|s| s.parse::<i64>().unwrap());
+        let turns = path.chars().filter(|c| c.is_alphabetic()).map(Some).chain(std::iter::once(None));
+        for (steps, turn) in steps.zip(turns) {
+            for _ in 0..steps {
+                let (next, next_direction) = cube.next_location(coord, direction);
+                if grid.entry(next) == Some(&'#') {
+                    break;
+                }
+                coord = next;
+                direction = next_direction;
+            }
+            direction = match turn {
+                Some('L') => direction.turn_left(),
+                Some('R') => direction.turn_right(),
+                _ => direction,
+            };
+        }
+
+        assert_eq!((coord, direction), ((4, 6), Direction::North));
+        let facing = CARDINAL_DIRECTIONS.iter().position(|&d| d == direction).unwrap() as i64 + 3;
+        assert_eq!(1000 * (coord.0 + 1) + 4 * (coord.1 + 1) + facing % 4, 5031);
+    }
+
+    #[test]
+    fn example_seams() {
+        let cube = Cube::from_grid(&InputSnake::from_text(EXAMPLE).grid_snake()).unwrap();
+        assert_eq!(cube.next_location((5, 11), Direction::East), ((8, 14), Direction::South));
+        assert_eq!(cube.next_location((11, 10), Direction::South), ((7, 1), Direction::North));
+        assert_eq!(cube.next_location((1, 11), Direction::East), ((10, 15), Direction::West));
+        assert_eq!(cube.next_location((5, 1), Direction::East), ((5, 2), Direction::East));
+    }
+
+    #[test]
+    fn every_net_folds() {
+        for tiles in NETS {
+            for side in [1, 3] {
+                let grid = net(tiles, side);
+                let cube = Cube::from_grid(&grid).unwrap();
+
+                for face in 0..6 {
+                    let mut neighbours: Vec<usize> = CARDINAL_DIRECTIONS.iter().map(|&d| cube.seam(face, d).0).collect();
+                    neighbours.sort_unstable();
+                    neighbours.dedup();
+                    assert_eq!(neighbours.len(), 4, "{}", tiles);
+                    assert!(!neighbours.contains(&face));
+                }
+
+                for (coord, _) in grid.occupied().filter(|(_, &c)| c == '.') {
+                    for &direction in &CARDINAL_DIRECTIONS {
+                        let (next, next_direction) = cube.next_location(coord, direction);
+                        assert_eq!(grid.entry(next), Some(&'.'), "{} from {:?}", tiles, coord);
+                        assert_eq!(cube.next_location(next, next_direction.opposite()), (coord, direction.opposite()));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn from_grid_rejects_other_shapes() {
+        assert_eq!(Cube::from_grid(&net("######", 2)), Err(CubeError::NotANet));
+        assert_eq!(Cube::from_grid(&net("####\n.##.", 2)), Err(CubeError::NotANet));
+        assert_eq!(Cube::from_grid(&net("###", 2)), Err(CubeError::NotSixSquares(12)));
+        assert_eq!(Cube::from_grid(&net("##.#\n.###", 1)), Err(CubeError::NotANet));
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use crate::timing::{self, Report};
+
+/// A puzzle solution split into its steps, so each one can be run and timed on its own.
+pub trait Day {
+    /// The puzzle input once parsed, which both parts share.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    fn part_one(input: &Self::Input<'_>) -> impl Display;
+
+    fn part_two(input: &Self::Input<'_>) -> impl Display;
+}
+
+/// A day with its input type erased, so every day of every year can be listed together.
+#[derive(Debug, Clone, Copy)]
+pub struct Solution {
+    year: u32,
+    day: u32,
+    bench: fn(&str, &str, usize) -> Report,
+}
+
+impl Solution {
+    pub fn new<D: Day>(year: u32, day: u32) -> Self {
+        Solution {
+            year,
+            day,
+            bench: timing::bench::<D>,
+        }
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Return the label of the day in reports, such as `2023/day7`.
+    pub fn label(&self) -> String {
+        format!("{}/day{}", self.year, self.day)
+    }
+
+    /// Run the day `runs` times on the input and report how long each step took.
+    pub fn bench(&self, input: &str, runs: usize) -> Report {
+        (self.bench)(&self.label(), input, runs)
+    }
+
+    /// Run the day on the input file, returning `None` if it does not exist.
+    pub fn bench_file<P: AsRef<Path>>(&self, path: P, runs: usize) -> Option<Report> {
+        let input = std::fs::read_to_string(path).ok()?;
+        Some(self.bench(&input, runs))
+    }
+}
+
+/// Return the nearest directory at or above `dir` with a workspace manifest.
+pub fn workspace_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|d| std::fs::read_to_string(d.join("Cargo.toml"))
+            .map(|manifest| manifest.contains("[workspace]"))
+            .unwrap_or(false))
+        .map(Path::to_path_buf)
+}
+
+/// Return `inputs/<year>/day<day>` below the workspace root, which holds the inputs of the day.
+pub fn input_dir(root: &Path, year: u32, day: u32) -> PathBuf {
+    root.join("inputs").join(year.to_string()).join(format!("day{}", day))
+}
+
+/// Return the directory with the inputs of the day in the workspace around the current directory.
+pub fn current_input_dir(year: u32, day: u32) -> PathBuf {
+    let cwd = std::env::current_dir().expect("Current directory is accessible");
+    let root = workspace_root(&cwd).expect("Current directory is inside the workspace");
+    input_dir(&root, year, day)
+}
+
+/// Return the example file of the day for the crate in `manifest_dir`, panicking if it is not
+/// inside the workspace.
+pub fn example_path(manifest_dir: &str, year: u32, day: u32, file: &str) -> PathBuf {
+    let root = workspace_root(Path::new(manifest_dir)).expect("Crate is inside the workspace");
+    input_dir(&root, year, day).join(file)
+}
+
+/// Run a part of the day on an example file, panicking with both answers if it is not the
+/// expected one.
+pub fn check_example<D: Day>(path: &Path, part: &str, run: fn(&D::Input<'_>) -> String, expected: &str) {
+    let text = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Unable to read example {}: {}", path.display(), e));
+    let actual = run(&D::parse(&text));
+    assert_eq!(actual, expected, "{} of {}", part, path.display());
+}
+
+/// Return the answer of `part_one` or `part_two` listed in the `expected_answers` file next to
+/// the example, as written by `aoc puzzle`, panicking if it is missing.
+pub fn expected_answer(example: &Path, part: &str) -> String {
+    let label = match part {
+        "part_one" => "part 1:",
+        "part_two" => "part 2:",
+        _ => panic!("Unknown part {}", part),
+    };
+    let path = example.with_file_name("expected_answers");
+    let answers = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Unable to read expected answers {}: {}", path.display(), e));
+    answers.lines()
+        .find_map(|line| line.strip_prefix(label))
+        .map(|answer| answer.trim().to_string())
+        .unwrap_or_else(|| panic!("No answer to {} in {}", part, path.display()))
+}
+
+/// Generate a `#[test]` for each example file and expected answer of a day's parts.
+///
+/// The example files are read from the day's input directory, and each part lists
+/// `file => answer` pairs:
+///
+/// ```ignore
+/// advent::examples!(Day8, 2023, 8;
+///     part_one: test_input => 2, test_input_2 => 6;
+///     part_two: test_input_3 => 6;
+/// );
+/// ```
+///
+/// A file without an answer, such as `part_one: example_1;`, is checked against the answer of the
+/// part in the `expected_answers` file written by `aoc puzzle`.
+#[macro_export]
+macro_rules! examples {
+    ($day:ty, $year:expr, $number:expr; $($part:ident: $($file:ident $(=> $expected:expr)?),+;)+) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            $(
+                mod $part {
+                    use super::*;
+
+                    $(
+                        #[test]
+                        fn $file() {
+                            let path = $crate::day::example_path(env!("CARGO_MANIFEST_DIR"), $year, $number, stringify!($file));
+                            let expected: Option<String> = None $(.or(Some($expected.to_string())))?;
+                            let expected = expected
+                                .unwrap_or_else(|| $crate::day::expected_answer(&path, stringify!($part)));
+                            $crate::day::check_example::<$day>(
+                                &path,
+                                stringify!($part),
+                                |input| <$day as $crate::day::Day>::$part(input).to_string(),
+                                &expected);
+                        }
+                    )+
+                }
+            )+
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expected_answer_reads_the_file_written_by_puzzle() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("expected_answers"), "part 1: 50\npart 2: 29\n").unwrap();
+
+        let example = dir.path().join("example_1");
+        assert_eq!(expected_answer(&example, "part_one"), "50");
+        assert_eq!(expected_answer(&example, "part_two"), "29");
+    }
+
+    #[test]
+    #[should_panic(expected = "No answer to part_two")]
+    fn expected_answer_panics_without_the_part() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("expected_answers"), "part 1: 50\n").unwrap();
+
+        expected_answer(&dir.path().join("example_1"), "part_two");
+    }
+}
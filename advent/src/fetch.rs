@@ -0,0 +1,326 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The site puzzle inputs are downloaded from.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request so the site owner knows who is making them.
+pub const USER_AGENT: &str = concat!(
+    "github.com/Odoh/advent-code advent/", env!("CARGO_PKG_VERSION"),
+    " by joshresch.cpp@gmail.com");
+
+/// The shortest time between two requests to the site.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The file below the cache which records when the last request was sent, so separate runs share the rate limit.
+pub const LAST_REQUEST_FILE: &str = ".last_request";
+
+/// Part of the body returned when an input is requested before its puzzle unlocks.
+const TOO_EARLY_BODY: &str = "Please don't repeatedly request this endpoint before it unlocks";
+
+/// Part of the body returned when the session cookie is missing or expired.
+const LOGGED_OUT_BODY: &str = "Please log in";
+
+/// Downloads puzzle inputs, caching each one on disk so it is only ever requested once.
+pub struct Client {
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    min_interval: Duration,
+    agent: ureq::Agent,
+}
+
+/// The reason an input could not be fetched.
+#[derive(Debug)]
+pub enum FetchError {
+    /// Reading or writing the cache failed.
+    Io(io::Error),
+    /// The puzzle has not unlocked yet, or does not exist.
+    NotUnlocked { year: u32, day: u32 },
+    /// The session cookie was rejected.
+    LoggedOut,
+    /// The site answered with an unexpected status.
+    Status { code: u16, body: String },
+    /// The request never got an answer.
+    Transport(String),
+}
+
+impl Client {
+    /// Create a client for the site which authenticates with the `session` cookie.
+    pub fn new(session: &str) -> Self {
+        Client {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.trim().to_string(),
+            cache_dir: PathBuf::from("inputs"),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Create a client with the session cookie stored in a file, such as `.session_id`.
+    pub fn from_session_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let session = fs::read_to_string(path)?;
+        Ok(Client::new(&session))
+    }
+
+    /// Send requests to another site, such as a local stub server.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Cache inputs below `cache_dir` instead of `inputs`.
+    pub fn with_cache_dir<P: AsRef<Path>>(mut self, cache_dir: P) -> Self {
+        self.cache_dir = cache_dir.as_ref().to_path_buf();
+        self
+    }
+
+    /// Wait at least `min_interval` between two requests.
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Return where the input of the day is cached, `<year>/day<day>/input` below the cache.
+    pub fn cache_path(&self, year: u32, day: u32) -> PathBuf {
+        self.cache_dir.join(year.to_string()).join(format!("day{}", day)).join("input")
+    }
+
+    /// Return where the time of the last request is recorded, `LAST_REQUEST_FILE` below the cache.
+    pub fn last_request_path(&self) -> PathBuf {
+        self.cache_dir.join(LAST_REQUEST_FILE)
+    }
+
+    /// Return the input of the day, downloading it only if it is not cached yet.
+    pub fn input(&mut self, year: u32, day: u32) -> Result<String, FetchError> {
+        let path = self.cache_path(year, day);
+        if path.exists() {
+            return fs::read_to_string(&path).map_err(FetchError::Io);
+        }
+
+        let body = self.get(&format!("/{}/day/{}/input", year, day))
+            .map_err(|e| e.for_day(year, day))?;
+        if body.contains(TOO_EARLY_BODY) {
+            return Err(FetchError::NotUnlocked { year, day });
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(FetchError::Io)?;
+        }
+        fs::write(&path, &body).map_err(FetchError::Io)?;
+        Ok(body)
+    }
+
+    /// Request the page at `path`, waiting first if the previous request was too recent.
+    pub(crate) fn get(&mut self, path: &str) -> Result<String, FetchError> {
+        self.wait_for_turn().map_err(FetchError::Io)?;
+        let request = self.agent.get(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session));
+        response_body(request.call())
+    }
+
+    /// Post the form to `path`, waiting first if the previous request was too recent.
+    pub(crate) fn post_form(&mut self, path: &str, form: &[(&str, &str)]) -> Result<String, FetchError> {
+        self.wait_for_turn().map_err(FetchError::Io)?;
+        let request = self.agent.post(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session));
+        response_body(request.send_form(form))
+    }
+
+    /// Sleep until `min_interval` has passed since the last request recorded on disk, then record this one.
+    fn wait_for_turn(&self) -> io::Result<()> {
+        let path = self.last_request_path();
+        let last_request = fs::read_to_string(&path).ok()
+            .and_then(|millis| millis.trim().parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(last_request) = last_request {
+            let elapsed = SystemTime::now().duration_since(last_request).unwrap_or_default();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&path, now.as_millis().to_string())
+    }
+}
+
+/// Return the body of a successful response, or the error it describes.
+fn response_body(result: Result<ureq::Response, ureq::Error>) -> Result<String, FetchError> {
+    match result {
+        Ok(response) => response.into_string().map_err(FetchError::Io),
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            if body.contains(LOGGED_OUT_BODY) {
+                Err(FetchError::LoggedOut)
+            } else {
+                Err(FetchError::Status { code, body })
+            }
+        },
+        Err(ureq::Error::Transport(transport)) => Err(FetchError::Transport(transport.to_string())),
+    }
+}
+
+impl FetchError {
+    /// Treat a 404, or the body asking not to request early, as the day not being unlocked.
+    pub(crate) fn for_day(self, year: u32, day: u32) -> Self {
+        match self {
+            FetchError::Status { code: 404, .. } => FetchError::NotUnlocked { year, day },
+            FetchError::Status { ref body, .. } if body.contains(TOO_EARLY_BODY) => FetchError::NotUnlocked { year, day },
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Io(e) => write!(f, "{}", e),
+            FetchError::NotUnlocked { year, day } => write!(f, "{} day {} has not unlocked yet", year, day),
+            FetchError::LoggedOut => write!(f, "the session cookie was rejected"),
+            FetchError::Status { code, body } => write!(f, "unexpected status {}: {}", code, body.trim()),
+            FetchError::Transport(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// A local server standing in for the site in tests.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Instant;
+
+    /// A request the stub server received.
+    #[derive(Debug)]
+    pub struct Received {
+        pub url: String,
+        pub cookie: Option<String>,
+        pub user_agent: Option<String>,
+        pub body: String,
+        pub at: Instant,
+    }
+
+    /// Start a server which answers every request with `status` and `response`.
+    pub fn stub_server(status: u16, response: &'static str) -> (String, Arc<Mutex<Vec<Received>>>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&received);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let mut body = String::new();
+                let _ = request.as_reader().read_to_string(&mut body);
+                let header = |name: &'static str| request.headers().iter()
+                    .find(|h| h.field.equiv(name))
+                    .map(|h| h.value.to_string());
+                log.lock().unwrap().push(Received {
+                    url: request.url().to_string(),
+                    cookie: header("Cookie"),
+                    user_agent: header("User-Agent"),
+                    body,
+                    at: Instant::now(),
+                });
+                let response = tiny_http::Response::from_string(response).with_status_code(status);
+                let _ = request.respond(response);
+            }
+        });
+        (url, received)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::stub::stub_server;
+
+    fn client(url: &str, cache_dir: &Path) -> Client {
+        Client::new("abc123\n")
+            .with_base_url(url)
+            .with_cache_dir(cache_dir)
+            .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn input_is_downloaded_once_and_cached() {
+        let (url, received) = stub_server(200, "1 2 3\n");
+        let cache = tempfile::tempdir().unwrap();
+        let mut client = client(&url, cache.path());
+
+        assert_eq!(client.input(2023, 1).unwrap(), "1 2 3\n");
+        assert_eq!(client.input(2023, 1).unwrap(), "1 2 3\n");
+        assert_eq!(fs::read_to_string(cache.path().join("2023").join("day1").join("input")).unwrap(), "1 2 3\n");
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].url, "/2023/day/1/input");
+        assert_eq!(received[0].cookie.as_deref(), Some("session=abc123"));
+        assert_eq!(received[0].user_agent.as_deref(), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn too_early_body_is_not_unlocked() {
+        let (url, _) = stub_server(200, "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time.");
+        let cache = tempfile::tempdir().unwrap();
+        let mut client = client(&url, cache.path());
+
+        assert!(matches!(client.input(2023, 25), Err(FetchError::NotUnlocked { year: 2023, day: 25 })));
+        assert!(!client.cache_path(2023, 25).exists());
+    }
+
+    #[test]
+    fn not_found_is_not_unlocked() {
+        let (url, received) = stub_server(404, "404 Not Found");
+        let cache = tempfile::tempdir().unwrap();
+        let mut client = client(&url, cache.path());
+
+        assert!(matches!(client.input(2023, 2), Err(FetchError::NotUnlocked { .. })));
+        assert!(matches!(client.input(2023, 2), Err(FetchError::NotUnlocked { .. })));
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rejected_session_is_logged_out() {
+        let (url, _) = stub_server(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.");
+        let cache = tempfile::tempdir().unwrap();
+        let mut client = client(&url, cache.path());
+
+        assert!(matches!(client.input(2023, 3), Err(FetchError::LoggedOut)));
+    }
+
+    #[test]
+    fn requests_are_rate_limited() {
+        let (url, received) = stub_server(200, "input");
+        let cache = tempfile::tempdir().unwrap();
+        let mut client = client(&url, cache.path()).with_min_interval(Duration::from_millis(100));
+
+        client.input(2023, 4).unwrap();
+        client.input(2023, 5).unwrap();
+
+        let received = received.lock().unwrap();
+        assert!(received[1].at - received[0].at >= Duration::from_millis(90));
+    }
+
+    #[test]
+    fn rate_limit_is_shared_through_the_cache() {
+        let (url, received) = stub_server(200, "input");
+        let cache = tempfile::tempdir().unwrap();
+
+        client(&url, cache.path()).with_min_interval(Duration::from_millis(100)).input(2023, 4).unwrap();
+        assert!(cache.path().join(LAST_REQUEST_FILE).exists());
+        client(&url, cache.path()).with_min_interval(Duration::from_millis(100)).input(2023, 5).unwrap();
+
+        let received = received.lock().unwrap();
+        assert!(received[1].at - received[0].at >= Duration::from_millis(90));
+    }
+}
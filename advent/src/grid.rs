@@ -1,25 +1,38 @@
-use std::collections::{HashMap, HashSet};
-use itertools::Itertools;
-use pancurses::Window;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 use log::debug;
 
 pub use pancurses::Input;
+
+use crate::point::{Heading, Point};
+use crate::{FromGridChar, InputSnake, UnknownTileError};
+
+pub mod screen;
+pub mod tiles;
+
+pub use screen::{DrawType, ReadProxy, Screen};
+
 pub type Coord = (i64, i64);
 
-pub const DRAW_CHAR: fn (Coord, Option<&char>) -> Option<char> = |_, c| c.map(|&c| c);
+pub const DRAW_CHAR: fn (Coord, Option<&char>) -> Option<char> = |_, c| c.copied();
 
+/// Entries at coordinates within bounds which grow to enclose them.
+///
+/// Grids are equal when they have the same entries and, unless they are empty, the same bounds.
+#[derive(Clone)]
 pub struct Grid<T> {
     coord_to_entry: HashMap<Coord, T>,
-    not_drawn_coords: HashSet<Coord>,
+    /// The coordinates of every entry by value, in row-major order.
+    entry_to_coords: HashMap<T, BTreeSet<Coord>>,
     min_x: i64, max_x: i64,
     min_y: i64, max_y: i64,
-    window: Option<Window>,
-    input_window: Option<Window>,
 }
 
-pub enum DrawType<'a> {
-    Curses(&'a Window),
-    StdOut,
+/// An entry which is displayed as a single character.
+pub trait GridChar {
+    fn grid_char(&self) -> char;
 }
 
 pub const CARDINAL_DIRECTIONS: [Direction; 4] = [
@@ -40,7 +53,7 @@ const ALL_DIRECTIONS: [Direction; 8] = [
     Direction::DownRight,
 ];
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Direction {
     Left,
     Right,
@@ -52,32 +65,167 @@ pub enum Direction {
     DownRight,
 }
 
-pub struct GridIterator<'a, T> 
-    where T: std::fmt::Debug + Eq + PartialEq {
+/// The occupied entries of a grid in row-major order.
+pub struct GridIterator<'a, T> {
+    occupied: Occupied<'a, T>,
+}
 
+/// The cells of a rectangle of a grid in row-major order, with `None` for holes.
+pub struct Cells<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Coord,
+    columns: i64,
+    /// The index of the next cell from the front, counting row by row from the origin.
+    front: i64,
+    /// One past the index of the next cell from the back.
+    back: i64,
+}
+
+/// The occupied cells of a grid in row-major order.
+pub struct Occupied<'a, T> {
+    cells: Cells<'a, T>,
+}
+
+/// The rows or columns of a grid, each as its cells.
+pub struct Lines<'a, T> {
+    grid: &'a Grid<T>,
+    axis: Axis,
+    front: i64,
+    back: i64,
+}
+
+/// The entries from a coordinate outwards by a step until it leaves the bounds, skipping holes.
+pub struct Ray<'a, T> {
     grid: &'a Grid<T>,
     coord: Coord,
+    step: Coord,
 }
 
-pub struct GridCoordinateIterator<'a, T>
-    where T: std::fmt::Debug + Eq + PartialEq {
+/// Whether cells go along a row or down a column.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Axis {
+    Row,
+    Column,
+}
 
-    grid_iter: GridIterator<'a, T>,
+/// Consecutive cells of a row or column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span<'a, T> {
+    pub start: Coord,
+    pub axis: Axis,
+    pub cells: Vec<&'a T>,
 }
 
-impl <'a, T> GridIterator<'a, T>
-    where T: std::fmt::Debug + Eq + PartialEq {
+/// Where coordinates end up when each empty row and column of a grid is grown into `factor` of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expansion {
+    /// The empty rows, in order.
+    pub rows: Vec<i64>,
+    /// The empty columns, in order.
+    pub columns: Vec<i64>,
+    pub factor: i64,
+}
 
+impl <'a, T> GridIterator<'a, T> {
     pub fn new(grid: &'a Grid<T>) -> GridIterator<'a, T> {
         GridIterator {
+            occupied: Occupied { cells: Cells::all(grid) },
+        }
+    }
+
+    /// Return the occupied entries with their coordinates.
+    pub fn coords(self) -> Occupied<'a, T> {
+        self.occupied
+    }
+}
+
+impl <'a, T> Cells<'a, T> {
+    fn all(grid: &'a Grid<T>) -> Self {
+        let (rows, columns) = grid.size();
+        Cells::rectangle(grid, grid.min_xy(), rows, columns)
+    }
+
+    fn rectangle(grid: &'a Grid<T>, origin: Coord, rows: i64, columns: i64) -> Self {
+        Cells {
+            grid,
+            origin,
+            columns,
+            front: 0,
+            back: rows * columns,
+        }
+    }
+
+    fn cell(&self, index: i64) -> (Coord, Option<&'a T>) {
+        let coord = (self.origin.0 + index / self.columns, self.origin.1 + index % self.columns);
+        (coord, self.grid.coord_to_entry.get(&coord))
+    }
+}
+
+impl <'a, T> Span<'a, T> {
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Return the coordinate of each cell of the span in order.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + '_ {
+        let step = match self.axis {
+            Axis::Row => (0, 1),
+            Axis::Column => (1, 0),
+        };
+        (0..self.len() as i64).map(move |i| (self.start.0 + step.0 * i, self.start.1 + step.1 * i))
+    }
+
+    /// Return the entries next to the span which are not part of it, in row-major order.
+    pub fn neighbors<'g>(&self, grid: &'g Grid<T>, eight_way: bool) -> Vec<(Coord, &'g T)> {
+        let directions: &[Direction] = if eight_way { &ALL_DIRECTIONS } else { &CARDINAL_DIRECTIONS };
+        let own: HashSet<Coord> = self.coords().collect();
+        let neighbors: BTreeSet<Coord> = self.coords()
+            .flat_map(|coord| directions.iter().map(move |d| d.next_coord(coord)))
+            .filter(|coord| !own.contains(coord))
+            .collect();
+        neighbors.into_iter()
+            .filter_map(|coord| grid.coord_to_entry.get(&coord).map(|entry| (coord, entry)))
+            .collect()
+    }
+}
+
+impl <'a> Span<'a, char> {
+    /// Return the characters of the span as a string.
+    pub fn text(&self) -> String {
+        self.cells.iter().copied().collect()
+    }
+}
+
+impl Expansion {
+    /// Return where the coordinate ends up, having moved `factor - 1` for every empty row above it
+    /// and every empty column to the left of it.
+    pub fn coord(&self, (x, y): Coord) -> Coord {
+        let before = |lines: &[i64], index: i64| lines.partition_point(|&line| line < index) as i64;
+        (x + before(&self.rows, x) * (self.factor - 1), y + before(&self.columns, y) * (self.factor - 1))
+    }
+}
+
+impl <'a, T> Lines<'a, T> {
+    fn new(grid: &'a Grid<T>, axis: Axis) -> Self {
+        let (rows, columns) = grid.size();
+        Lines {
             grid,
-            coord: grid.min_xy(),
+            axis,
+            front: 0,
+            back: if axis == Axis::Row { rows } else { columns },
         }
     }
 
-    pub fn coords(self) -> GridCoordinateIterator<'a, T> {
-        GridCoordinateIterator {
-            grid_iter: self,
+    fn line(&self, index: i64) -> Cells<'a, T> {
+        let (rows, columns) = self.grid.size();
+        let (min_x, min_y) = self.grid.min_xy();
+        match self.axis {
+            Axis::Row => Cells::rectangle(self.grid, (min_x + index, min_y), 1, columns),
+            Axis::Column => Cells::rectangle(self.grid, (min_x, min_y + index), rows, 1),
         }
     }
 }
@@ -88,16 +236,7 @@ impl Direction {
     }
 
     pub fn next_coord_by_value(self, coord: Coord, value: i64) -> Coord {
-        match self {
-            Direction::Up => (coord.0 - value, coord.1),
-            Direction::Down => (coord.0 + value, coord.1),
-            Direction::Left => (coord.0, coord.1 - value),
-            Direction::Right => (coord.0, coord.1 + value),
-            Direction::DownRight => (coord.0 - value, coord.1 - value),
-            Direction::DownLeft => (coord.0 + value, coord.1 - value),
-            Direction::UpRight => (coord.0 - value, coord.1 + value),
-            Direction::UpLeft => (coord.0 + value, coord.1 + value),
-        }
+        self.step(Point(coord), value).0
     }
 
     pub fn get_opposite(&self) -> Direction {
@@ -115,18 +254,38 @@ impl Direction {
     }
 }
 
-impl <'a> PartialEq for DrawType<'a> {
-    fn eq(&self, other: &DrawType) -> bool {
-        match (self, other) {
-            (DrawType::Curses(_), DrawType::Curses(_)) => true,
-            (DrawType::StdOut, DrawType::StdOut) => true,
-            _ => false,
-        }
+impl <T> Default for Grid<T>
+    where T: std::fmt::Debug + Eq + PartialEq {
+
+    fn default() -> Self {
+        Grid::new()
     }
 }
 
-pub struct ReadProxy {
-    input_window: Window,
+impl <T> Grid<T> {
+    pub fn min_xy(&self) -> (i64, i64) {
+        (self.min_x, self.min_y)
+    }
+
+    pub fn max_xy(&self) -> (i64, i64) {
+        (self.max_x, self.max_y)
+    }
+
+    /// Return whether the coordinate is within the bounds, which an empty grid has none of.
+    pub fn contains(&self, (x, y): Coord) -> bool {
+        !self.coord_to_entry.is_empty()
+            && (self.min_x..=self.max_x).contains(&x)
+            && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    /// Return the number of rows and columns within the bounds, which is none for an empty grid.
+    fn size(&self) -> (i64, i64) {
+        if self.coord_to_entry.is_empty() {
+            (0, 0)
+        } else {
+            (self.max_x - self.min_x + 1, self.max_y - self.min_y + 1)
+        }
+    }
 }
 
 impl <T>  Grid<T> 
@@ -135,11 +294,9 @@ impl <T>  Grid<T>
     pub fn new() -> Grid<T> {
         Grid {
             coord_to_entry: HashMap::new(),
-            not_drawn_coords: HashSet::new(),
+            entry_to_coords: HashMap::new(),
             min_x: 0, max_x: 0,
             min_y: 0, max_y: 0,
-            window: None,
-            input_window: None,
         }
     }
 
@@ -153,12 +310,19 @@ impl <T>  Grid<T>
         (next_coord, next_entry)
     }
 
-    pub fn entries(&self) -> Vec<&T> {
-        self.coord_to_entry.values().collect()
+    /// Return every entry in no particular order.
+    pub fn entries(&self) -> impl Iterator<Item = &T> + '_ {
+        self.coord_to_entry.values()
+    }
+
+    /// Return every entry with its coordinate in no particular order.
+    pub fn locations(&self) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.coord_to_entry.iter().map(|(c, t)| (*c, t))
     }
 
-    pub fn locations(&self) -> Vec<(Coord, &T)> {
-        self.coord_to_entry.iter().map(|(c, t)| (*c, t)).collect()
+    /// Return the first entry in row-major order which matches the predicate.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(Coord, &T)> {
+        self.occupied().find(|(_, entry)| predicate(entry))
     }
 
     pub fn directional_entries(&self, mut coord: Coord, direction: Direction) -> Vec<&T> {
@@ -203,160 +367,966 @@ impl <T>  Grid<T>
             .collect()
     }
 
-    pub fn min_xy(&self) -> (i64, i64) {
-        (self.min_x, self.min_y)
+    /// Print every cell within the bounds to stdout, with a space where there is no character.
+    pub fn draw(&self, entry_char: impl Fn(Coord, Option<&T>) -> Option<char>) {
+        for row in self.rows() {
+            let line: String = row.map(|(coord, entry)| entry_char(coord, entry).unwrap_or(' ')).collect();
+            println!("{}", line);
+        }
     }
 
-    pub fn max_xy(&self) -> (i64, i64) {
-        (self.max_x, self.max_y)
+    /// Return the occupied entries in row-major order.
+    pub fn iter(&self) -> GridIterator<'_, T> {
+        GridIterator::new(self)
+    }
+
+    /// Return every cell within the bounds in row-major order, with `None` for holes.
+    pub fn cells(&self) -> Cells<'_, T> {
+        Cells::all(self)
+    }
+
+    /// Return the occupied cells in row-major order.
+    pub fn occupied(&self) -> Occupied<'_, T> {
+        Occupied { cells: Cells::all(self) }
     }
 
+    /// Return the entries from the coordinate outwards in the direction, not including it.
+    pub fn ray(&self, coord: Coord, direction: Direction) -> Ray<'_, T> {
+        self.ray_by(coord, direction.next_coord((0, 0)))
+    }
+
+    /// Return the entries from the coordinate outwards by the step, not including it.
+    ///
+    /// Panics if the step is (0, 0).
+    pub fn ray_by(&self, coord: Coord, step: Coord) -> Ray<'_, T> {
+        assert_ne!(step, (0, 0), "A ray needs to move");
+        Ray { grid: self, coord, step }
+    }
+
+    /// Return the first entry from the coordinate outwards in the direction which matches the
+    /// predicate.
+    pub fn first_visible(&self, coord: Coord, direction: Direction, mut predicate: impl FnMut(&T) -> bool) -> Option<(Coord, &T)> {
+        self.ray(coord, direction).find(|(_, entry)| predicate(entry))
+    }
+
+    /// Return the first entry matching the predicate in each of the eight directions which has one.
+    pub fn all_first_visible(&self, coord: Coord, mut predicate: impl FnMut(&T) -> bool) -> Vec<(Direction, Coord, &T)> {
+        ALL_DIRECTIONS.iter()
+            .filter_map(|&direction| self.first_visible(coord, direction, &mut predicate)
+                .map(|(c, entry)| (direction, c, entry)))
+            .collect()
+    }
+
+    /// Return the nearest entry matching the predicate along every line of sight from the
+    /// coordinate at any angle, in row-major order. Entries along the same reduced slope hide
+    /// the ones behind them.
+    pub fn visible_from(&self, coord: Coord, mut predicate: impl FnMut(&T) -> bool) -> Vec<(Coord, &T)> {
+        let mut nearest: HashMap<Coord, (i64, Coord, &T)> = HashMap::new();
+        for (c, entry) in self.occupied().filter(|&(c, entry)| c != coord && predicate(entry)) {
+            let (dx, dy) = (c.0 - coord.0, c.1 - coord.1);
+            let distance = gcd(dx, dy);
+            let slope = (dx / distance, dy / distance);
+            let closest = nearest.entry(slope).or_insert((distance, c, entry));
+            if distance < closest.0 {
+                *closest = (distance, c, entry);
+            }
+        }
+
+        let mut visible: Vec<(Coord, &T)> = nearest.into_values().map(|(_, c, entry)| (c, entry)).collect();
+        visible.sort_unstable_by_key(|&(c, _)| c);
+        visible
+    }
+
+    /// Return each longest run of entries matching the predicate along every row or column, in
+    /// the order of the rows or columns. Holes end a run.
+    pub fn runs(&self, mut predicate: impl FnMut(&T) -> bool, axis: Axis) -> Vec<Span<'_, T>> {
+        let mut spans = Vec::new();
+        for line in Lines::new(self, axis) {
+            let mut span: Option<Span<T>> = None;
+            for (coord, entry) in line {
+                match entry.filter(|e| predicate(e)) {
+                    Some(entry) => span.get_or_insert_with(|| Span { start: coord, axis, cells: Vec::new() })
+                        .cells
+                        .push(entry),
+                    None => spans.extend(span.take()),
+                }
+            }
+            spans.extend(span);
+        }
+        spans
+    }
+
+    /// Return each row within the bounds, from the smallest x.
+    pub fn rows(&self) -> Lines<'_, T> {
+        Lines::new(self, Axis::Row)
+    }
+
+    /// Return each column within the bounds, from the smallest y.
+    pub fn columns(&self) -> Lines<'_, T> {
+        Lines::new(self, Axis::Column)
+    }
+
+    /// Return every row within the bounds whose cells are all holes or match the predicate.
+    pub fn empty_rows(&self, predicate: impl FnMut(&T) -> bool) -> Vec<i64> {
+        self.empty_lines(Axis::Row, predicate)
+    }
+
+    /// Return every column within the bounds whose cells are all holes or match the predicate.
+    pub fn empty_columns(&self, predicate: impl FnMut(&T) -> bool) -> Vec<i64> {
+        self.empty_lines(Axis::Column, predicate)
+    }
+
+    /// Return where the entries would be if each empty row and column were grown into `factor` of
+    /// them, without adding any cells.
+    pub fn expansion(&self, factor: i64, mut predicate: impl FnMut(&T) -> bool) -> Expansion {
+        Expansion {
+            rows: self.empty_rows(&mut predicate),
+            columns: self.empty_columns(&mut predicate),
+            factor,
+        }
+    }
+
+    fn empty_lines(&self, axis: Axis, mut predicate: impl FnMut(&T) -> bool) -> Vec<i64> {
+        let first = if axis == Axis::Row { self.min_x } else { self.min_y };
+        Lines::new(self, axis)
+            .enumerate()
+            .filter_map(|(i, mut line)| {
+                line.all(|(_, entry)| entry.is_none_or(&mut predicate)).then_some(first + i as i64)
+            })
+            .collect()
+    }
+}
+
+/// The methods which read the index from each value to its coordinates, or keep it up to date.
+impl <T> Grid<T>
+    where T: std::fmt::Debug + Eq + PartialEq + Hash + Clone {
+
+    /// Return the coordinates of every entry equal to the value in row-major order.
+    pub fn positions<'a>(&'a self, value: &T) -> impl DoubleEndedIterator<Item = Coord> + 'a {
+        self.entry_to_coords.get(value)
+            .into_iter()
+            .flat_map(|coords| coords.iter().copied())
+    }
+
+    /// Return the number of entries which match the predicate.
+    pub fn count_where(&self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        self.entry_to_coords.iter()
+            .filter(|(entry, _)| predicate(entry))
+            .map(|(_, coords)| coords.len())
+            .sum()
+    }
+
+    /// Return how many times each value is in the grid.
+    pub fn histogram(&self) -> HashMap<&T, usize> {
+        self.entry_to_coords.iter()
+            .map(|(entry, coords)| (entry, coords.len()))
+            .collect()
+    }
+
+    /// Return the smallest and largest coordinates of the entries which match the predicate.
+    pub fn bounds_of(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(Coord, Coord)> {
+        self.entry_to_coords.iter()
+            .filter(|(entry, _)| predicate(entry))
+            .flat_map(|(_, coords)| coords.iter())
+            .fold(None, |bounds, &(x, y)| match bounds {
+                None => Some(((x, y), (x, y))),
+                Some(((min_x, min_y), (max_x, max_y))) => Some(((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))),
+            })
+    }
+
+    /// Add the entry at the coordinate, growing the bounds to enclose it.
+    ///
+    /// The bounds of an empty grid are (0, 0), so the first entry sets both of its bounds.
     pub fn add_entry(&mut self, coord: Coord, entry: T) {
-        if coord.0 < self.min_x {
+        if self.coord_to_entry.is_empty() {
             self.min_x = coord.0;
-        } else if coord.0 > self.max_x {
             self.max_x = coord.0;
-        }
-
-        if coord.1 < self.min_y {
             self.min_y = coord.1;
-        } else if coord.1 > self.max_y {
             self.max_y = coord.1;
         }
+        self.min_x = self.min_x.min(coord.0);
+        self.max_x = self.max_x.max(coord.0);
+        self.min_y = self.min_y.min(coord.1);
+        self.max_y = self.max_y.max(coord.1);
+        self.place(coord, entry);
+    }
+
+    /// Remove the entry at the coordinate, leaving the bounds as they are.
+    pub fn remove_entry(&mut self, coord: Coord) -> Option<T> {
+        let entry = self.coord_to_entry.remove(&coord)?;
+        self.remove_position(&entry, coord);
+        Some(entry)
+    }
 
+    /// Put the entry at the coordinate without changing the bounds.
+    fn place(&mut self, coord: Coord, entry: T) {
         debug!("Add entry: {:?} at {:?}", entry, coord);
-        self.coord_to_entry.insert(coord, entry);
-        self.not_drawn_coords.insert(coord);
+        self.entry_to_coords.entry(entry.clone()).or_default().insert(coord);
+        if let Some(previous) = self.coord_to_entry.insert(coord, entry) {
+            if self.coord_to_entry[&coord] != previous {
+                self.remove_position(&previous, coord);
+            }
+        }
     }
 
-    pub fn init_curses(&mut self) {
-        let stdscr = pancurses::initscr();
-        self.window = Some(stdscr);
+    /// Return the coordinate of the entry, which is expected to be in the grid exactly once.
+    pub fn position(&self, entry: T) -> Coord {
+        let coords = &self.entry_to_coords[&entry];
+        debug_assert_eq!(coords.len(), 1);
+
+        *coords.iter().next().unwrap()
     }
 
-    pub fn init_curses_input(&mut self, x: i32, y: i32) {
-        let input_window = pancurses::newwin(1, 1, x, y);
-        input_window.nodelay(true);
-        input_window.keypad(true);
-        self.input_window = Some(input_window);
+    /// Insert a row at `x`, moving that row and every row after it down by one. The new row is
+    /// filled across the columns of the bounds, or left as holes without a fill.
+    pub fn insert_row(&mut self, x: i64, fill: Option<T>) {
+        self.insert_line(Axis::Row, x, fill);
     }
 
-    pub fn end_curses(&mut self) {
-        if let Some(_) = self.window.take() {
-            pancurses::endwin();
-        }
+    /// Insert a column at `y`, moving that column and every column after it right by one. The new
+    /// column is filled across the rows of the bounds, or left as holes without a fill.
+    pub fn insert_column(&mut self, y: i64, fill: Option<T>) {
+        self.insert_line(Axis::Column, y, fill);
     }
 
-    pub fn draw(&mut self, entry_char: impl Fn(Coord, Option<&T>) -> Option<char>) {
-        for x in self.min_x..=self.max_x {
-            for y in self.min_y..=self.max_y {
-                let coord: Coord = (x, y);
+    /// Remove row `x`, moving every row after it up by one.
+    pub fn remove_row(&mut self, x: i64) {
+        self.remove_line(Axis::Row, x);
+    }
 
-                if self.draw_type() == DrawType::StdOut {
-                    let entry = self.coord_to_entry.get(&coord);
-                    if let Some(c) = entry_char(coord, entry) {
-                        print!("{}", c);
-                    } else {
-                        print!(" ");
-                    }
-                    continue;
-                }
+    /// Remove column `y`, moving every column after it left by one.
+    pub fn remove_column(&mut self, y: i64) {
+        self.remove_line(Axis::Column, y);
+    }
 
-                if self.not_drawn_coords.contains(&coord) {
-                    self.not_drawn_coords.remove(&coord);
+    /// Slide every movable entry in the direction until it reaches the edge of the bounds, a
+    /// blocker or a movable entry which has stopped. Anything else is free space, which swaps
+    /// places with the entries sliding over it.
+    ///
+    /// Return how many entries moved.
+    pub fn tilt(&mut self, direction: Direction, mut movable: impl FnMut(&T) -> bool, mut blocker: impl FnMut(&T) -> bool) -> usize {
+        let step = direction.unit();
+        let mut coords: Vec<Coord> = self.locations()
+            .filter(|&(_, entry)| movable(entry))
+            .map(|(coord, _)| coord)
+            .collect();
+        // the entries furthest along the direction stop first, so the rest can pile up behind them
+        coords.sort_unstable_by_key(|&(x, y)| -(x * step.row() + y * step.col()));
 
-                    if let DrawType::Curses(window) = self.draw_type() {
-                        let entry = self.coord_to_entry.get(&coord);
-                        if let Some(c) = entry_char(coord, entry) {
-                            window.mvaddch(y as i32, x as i32, c);
-                            window.refresh();
-                        }
-                    }
+        let mut moved = 0;
+        for coord in coords {
+            let mut end = coord;
+            loop {
+                let next = direction.next_coord(end);
+                if !self.contains(next) || self.entry(next).is_some_and(|e| movable(e) || blocker(e)) {
+                    break;
                 }
+                end = next;
             }
-            if self.draw_type() == DrawType::StdOut {
-                println!();
+            if end != coord {
+                self.swap(coord, end);
+                moved += 1;
             }
         }
+        moved
     }
 
-    pub fn draw_type(&self) -> DrawType {
-        if self.window.is_some() {
-            DrawType::Curses(self.window.as_ref().unwrap())
-        } else {
-            DrawType::StdOut
+    fn insert_line(&mut self, axis: Axis, index: i64, fill: Option<T>) {
+        let cells: Vec<Coord> = match axis {
+            Axis::Row => (self.min_y..=self.max_y).map(|y| (index, y)).collect(),
+            Axis::Column => (self.min_x..=self.max_x).map(|x| (x, index)).collect(),
+        };
+        let empty = self.coord_to_entry.is_empty();
+        self.remap(|(x, y)| match axis {
+            Axis::Row if x >= index => Some((x + 1, y)),
+            Axis::Column if y >= index => Some((x, y + 1)),
+            _ => Some((x, y)),
+        });
+        self.shift_bounds(axis, index, 1);
+
+        if let (Some(fill), false) = (fill, empty) {
+            for coord in cells {
+                self.add_entry(coord, fill.clone());
+            }
         }
     }
 
-    pub fn read_key(&self) -> Option<Input> {
-        self.input_window.as_ref().expect("Input window has been taken").getch()
+    fn remove_line(&mut self, axis: Axis, index: i64) {
+        self.remap(|(x, y)| match axis {
+            Axis::Row if x == index => None,
+            Axis::Row if x > index => Some((x - 1, y)),
+            Axis::Column if y == index => None,
+            Axis::Column if y > index => Some((x, y - 1)),
+            _ => Some((x, y)),
+        });
+        self.shift_bounds(axis, index, -1);
     }
 
-    pub fn read_proxy(&mut self) -> ReadProxy {
-        ReadProxy::new(self.input_window.take().expect("Input window was already taken"))
+    /// Move the bounds along the axis with the lines at and after the index.
+    fn shift_bounds(&mut self, axis: Axis, index: i64, delta: i64) {
+        let (min, max) = match axis {
+            Axis::Row => (&mut self.min_x, &mut self.max_x),
+            Axis::Column => (&mut self.min_y, &mut self.max_y),
+        };
+        if index < *min {
+            *min += delta;
+        }
+        if index <= *max {
+            *max += delta;
+        }
     }
 
-    pub fn iter(&self) -> GridIterator<T> {
-        GridIterator::new(self)
+    /// Move every entry to the coordinate the function gives it, dropping it for `None`, without
+    /// changing the bounds.
+    fn remap(&mut self, mut f: impl FnMut(Coord) -> Option<Coord>) {
+        let entries: Vec<(Coord, T)> = self.coord_to_entry.drain().collect();
+        self.entry_to_coords.clear();
+        for (coord, entry) in entries {
+            if let Some(coord) = f(coord) {
+                self.place(coord, entry);
+            }
+        }
     }
 
-    pub fn position(&self, entry: T) -> Coord {
-        let coords = self.coord_to_entry.iter()
-            .filter_map(|(c, v)| if *v == entry { Some(c) } else { None })
-            .collect_vec();
-        debug_assert_eq!(coords.len(), 1);
+    fn swap(&mut self, a: Coord, b: Coord) {
+        let (first, second) = (self.remove_entry(a), self.remove_entry(b));
+        if let Some(entry) = first {
+            self.place(b, entry);
+        }
+        if let Some(entry) = second {
+            self.place(a, entry);
+        }
+    }
 
-        **coords.get(0).unwrap()
+    fn remove_position(&mut self, entry: &T, coord: Coord) {
+        if let Some(coords) = self.entry_to_coords.get_mut(entry) {
+            coords.remove(&coord);
+            if coords.is_empty() {
+                self.entry_to_coords.remove(entry);
+            }
+        }
     }
 }
 
 
-const ITER_COMPLETE: Coord = (-1, -1);
-
-impl <'a, T: std::fmt::Debug + Eq + PartialEq> Iterator for GridIterator<'a, T> {
+impl <'a, T> Iterator for GridIterator<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<&'a T> {
-        if self.coord == ITER_COMPLETE {
-            return None
+        self.occupied.next().map(|(_, entry)| entry)
+    }
+}
+
+impl <'a, T> DoubleEndedIterator for GridIterator<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.occupied.next_back().map(|(_, entry)| entry)
+    }
+}
+
+impl <'a, T> Iterator for Cells<'a, T> {
+    type Item = (Coord, Option<&'a T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
         }
+        self.front += 1;
+        Some(self.cell(self.front - 1))
+    }
 
-        let (_min_x, min_y) = self.grid.min_xy();
-        let (max_x, max_y) = self.grid.max_xy();
-        let item = self.grid.entry(self.coord);
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front).max(0) as usize;
+        (len, Some(len))
+    }
+}
 
-        let (next_x, next_y) = if self.coord.0 == max_x && self.coord.1 == max_y {
-            ITER_COMPLETE
-        } else if self.coord.1 + 1 <= max_y {
-            (self.coord.0, self.coord.1 + 1)
-        } else {
-            (self.coord.0 + 1, min_y)
-        };
+impl <'a, T> DoubleEndedIterator for Cells<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.cell(self.back))
+    }
+}
+
+impl <'a, T> ExactSizeIterator for Cells<'a, T> {}
+
+impl <'a, T> Iterator for Occupied<'a, T> {
+    type Item = (Coord, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cells.find_map(|(coord, entry)| entry.map(|e| (coord, e)))
+    }
 
-        self.coord = (next_x, next_y);
-        item
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.cells.size_hint().1)
     }
 }
 
-impl <'a, T: std::fmt::Debug + Eq + PartialEq> Iterator for GridCoordinateIterator<'a, T> {
+impl <'a, T> DoubleEndedIterator for Occupied<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cells.by_ref().rev().find_map(|(coord, entry)| entry.map(|e| (coord, e)))
+    }
+}
+
+impl <'a, T> Iterator for Ray<'a, T> {
     type Item = (Coord, &'a T);
 
-    fn next(&mut self) -> Option<(Coord, &'a T)> {
-        let coord = self.grid_iter.coord;
-        let item = self.grid_iter.next();
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.coord = (self.coord.0 + self.step.0, self.coord.1 + self.step.1);
+            if !self.grid.contains(self.coord) {
+                return None;
+            }
+            if let Some(entry) = self.grid.coord_to_entry.get(&self.coord) {
+                return Some((self.coord, entry));
+            }
+        }
+    }
+}
+
+/// Return the greatest common divisor of the magnitudes, which is at least 1.
+fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
+
+impl <'a, T> Iterator for Lines<'a, T> {
+    type Item = Cells<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.line(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front).max(0) as usize;
+        (len, Some(len))
+    }
+}
+
+impl <'a, T> DoubleEndedIterator for Lines<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.line(self.back))
+    }
+}
+
+impl <'a, T> ExactSizeIterator for Lines<'a, T> {}
 
-        item.map(|i| (coord, i))
+impl Grid<char> {
+    /// Return every number written along a row, with the span of its digits. Runs of digits too
+    /// long for a `u64` are skipped.
+    pub fn number_spans(&self) -> Vec<(u64, Span<'_, char>)> {
+        self.runs(char::is_ascii_digit, Axis::Row)
+            .into_iter()
+            .filter_map(|span| span.text().parse().ok().map(|number| (number, span)))
+            .collect()
+    }
+}
+
+impl <T> PartialEq for Grid<T>
+    where T: PartialEq {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.coord_to_entry == other.coord_to_entry
+            && (self.coord_to_entry.is_empty() || (self.min_xy(), self.max_xy()) == (other.min_xy(), other.max_xy()))
+    }
+}
+
+impl <T> Eq for Grid<T>
+    where T: Eq {}
+
+impl <T> Hash for Grid<T>
+    where T: fmt::Debug + Eq + PartialEq + Hash {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if !self.coord_to_entry.is_empty() {
+            (self.min_xy(), self.max_xy()).hash(state);
+        }
+        self.occupied().for_each(|location| location.hash(state));
     }
 }
 
-impl ReadProxy {
-    pub fn new(input_window: Window) -> ReadProxy {
-        ReadProxy {
-            input_window,
+impl <T> fmt::Debug for Grid<T>
+    where T: fmt::Debug + Eq + PartialEq {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Grid")
+            .field("min", &self.min_xy())
+            .field("max", &self.max_xy())
+            .field("entries", &self.occupied().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Show each row within the bounds on its own line, with a space for each hole.
+impl <T> fmt::Display for Grid<T>
+    where T: GridChar + fmt::Debug + Eq + PartialEq {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for (_, entry) in row {
+                write!(f, "{}", entry.map_or(' ', GridChar::grid_char))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Read the grid the same way as `InputSnake::grid_snake`, ignoring a leading newline.
+impl <T> FromStr for Grid<T>
+    where T: FromGridChar + fmt::Debug + Eq + PartialEq + Hash + Clone {
+
+    type Err = UnknownTileError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        InputSnake::from_text(s).typed_grid_snake().map(|tiles| tiles.grid)
+    }
+}
+
+impl <T> GridChar for T
+    where T: FromGridChar {
+
+    fn grid_char(&self) -> char {
+        self.to_grid_char()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        proptest::sample::select(&ALL_DIRECTIONS[..])
+    }
+
+    fn coord() -> impl Strategy<Value = Coord> {
+        (-1000i64..1000, -1000i64..1000)
+    }
+
+    fn dense_grid(origin: Coord, rows: i64, columns: i64) -> Grid<Coord> {
+        let mut grid = Grid::new();
+        for x in origin.0..origin.0 + rows {
+            for y in origin.1..origin.1 + columns {
+                grid.add_entry((x, y), (x, y));
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn diagonals_combine_cardinals() {
+        let coord = (5, 5);
+        let combined = |a: Direction, b: Direction| b.next_coord(a.next_coord(coord));
+        assert_eq!(Direction::UpLeft.next_coord(coord), combined(Direction::Up, Direction::Left));
+        assert_eq!(Direction::UpRight.next_coord(coord), combined(Direction::Up, Direction::Right));
+        assert_eq!(Direction::DownLeft.next_coord(coord), combined(Direction::Down, Direction::Left));
+        assert_eq!(Direction::DownRight.next_coord(coord), combined(Direction::Down, Direction::Right));
+    }
+
+    #[test]
+    fn adjacent_locations_are_neighbours() {
+        let grid = dense_grid((0, 0), 3, 3);
+        assert_eq!(grid.all_adjacent_locations((1, 1)).len(), 8);
+        assert_eq!(grid.adjacent_cardinal_locations((0, 0)).len(), 2);
+        assert_eq!(grid.next_location((1, 1), Direction::UpLeft), ((0, 0), Some(&(0, 0))));
+        assert_eq!(grid.directional_entries((2, 2), Direction::UpLeft), vec![&(1, 1), &(0, 0)]);
+    }
+
+    #[test]
+    fn empty_grid_has_no_entries() {
+        let grid: Grid<char> = Grid::new();
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!((grid.min_xy(), grid.max_xy()), ((0, 0), (0, 0)));
+    }
+
+    #[test]
+    fn cells_include_holes() {
+        let mut grid = Grid::new();
+        grid.add_entry((-1, -1), 'a');
+        grid.add_entry((0, 1), 'b');
+
+        let cells: Vec<(Coord, Option<&char>)> = grid.cells().collect();
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[0], ((-1, -1), Some(&'a')));
+        assert_eq!(cells[1], ((-1, 0), None));
+        assert_eq!(cells[5], ((0, 1), Some(&'b')));
+        assert_eq!(grid.occupied().collect::<Vec<_>>(), vec![((-1, -1), &'a'), ((0, 1), &'b')]);
+        assert_eq!(grid.iter().rev().collect::<Vec<_>>(), vec![&'b', &'a']);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = dense_grid((1, -2), 2, 3);
+        let rows: Vec<Vec<Coord>> = grid.rows()
+            .map(|row| row.map(|(c, _)| c).collect())
+            .collect();
+        assert_eq!(rows, vec![vec![(1, -2), (1, -1), (1, 0)], vec![(2, -2), (2, -1), (2, 0)]]);
+
+        let mut columns = grid.columns();
+        assert_eq!(columns.len(), 3);
+        let last: Vec<Coord> = columns.next_back().unwrap().map(|(c, _)| c).collect();
+        assert_eq!(last, vec![(1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn queries_use_the_index() {
+        let grid = crate::InputSnake::from_text("#.#\n.S.\n#..").grid_snake();
+        assert_eq!(grid.positions(&'#').collect::<Vec<_>>(), vec![(0, 0), (0, 2), (2, 0)]);
+        assert_eq!(grid.positions(&'x').count(), 0);
+        assert_eq!(grid.position('S'), (1, 1));
+        assert_eq!(grid.find(|&c| c == '.'), Some(((0, 1), &'.')));
+        assert_eq!(grid.count_where(|&c| c != '#'), 6);
+        assert_eq!(grid.histogram(), HashMap::from([(&'#', 3), (&'.', 5), (&'S', 1)]));
+        assert_eq!(grid.bounds_of(|&c| c == '#'), Some(((0, 0), (2, 2))));
+        assert_eq!(grid.bounds_of(|&c| c == 'x'), None);
+    }
+
+    #[test]
+    fn replacing_an_entry_updates_the_index() {
+        let mut grid = Grid::new();
+        grid.add_entry((0, 0), 'a');
+        grid.add_entry((0, 1), 'a');
+        grid.add_entry((0, 0), 'b');
+        grid.add_entry((0, 1), 'a');
+        assert_eq!(grid.positions(&'a').collect::<Vec<_>>(), vec![(0, 1)]);
+        assert_eq!(grid.histogram(), HashMap::from([(&'a', 1), (&'b', 1)]));
+    }
+
+    #[test]
+    fn runs_end_at_rows_and_holes() {
+        let mut grid = crate::InputSnake::from_text("..12\n3.4.\n56..").grid_snake();
+        grid.add_entry((3, 1), '7');
+        let numbers: Vec<(u64, Coord)> = grid.number_spans().iter().map(|(n, s)| (*n, s.start)).collect();
+        assert_eq!(numbers, vec![(12, (0, 2)), (3, (1, 0)), (4, (1, 2)), (56, (2, 0)), (7, (3, 1))]);
+
+        let columns: Vec<String> = grid.runs(char::is_ascii_digit, Axis::Column).iter().map(Span::text).collect();
+        assert_eq!(columns, vec!["35", "67", "14", "2"]);
+    }
+
+    #[test]
+    fn number_spans_skip_numbers_too_long_for_u64() {
+        let grid = crate::InputSnake::from_text("99999999999999999999.7").grid_snake();
+        let numbers: Vec<(u64, Coord)> = grid.number_spans().iter().map(|(n, s)| (*n, s.start)).collect();
+        assert_eq!(numbers, vec![(7, (0, 21))]);
+    }
+
+    #[test]
+    fn span_neighbors() {
+        let grid = crate::InputSnake::from_text("a12b\ncdef").grid_snake();
+        let spans = grid.number_spans();
+        let span = &spans[0].1;
+        assert_eq!(span.coords().collect::<Vec<_>>(), vec![(0, 1), (0, 2)]);
+
+        let neighbors = |eight_way| span.neighbors(&grid, eight_way).into_iter().map(|(_, &c)| c).collect::<String>();
+        assert_eq!(neighbors(true), "abcdef");
+        assert_eq!(neighbors(false), "abde");
+    }
+
+    const ASTEROIDS: &str = r#"
+.#..#
+.....
+#####
+....#
+...##
+"#;
+
+    #[test]
+    fn rays_stop_at_the_bounds() {
+        let mut grid = crate::InputSnake::from_text("abcd\nefgh\nijkl").grid_snake();
+        let ray: Vec<(Coord, &char)> = grid.ray((0, 0), Direction::DownRight).collect();
+        assert_eq!(ray, vec![((1, 1), &'f'), ((2, 2), &'k')]);
+        assert_eq!(grid.ray((1, 1), Direction::Left).count(), 1);
+        assert_eq!(grid.ray_by((0, 0), (1, 3)).map(|(_, &c)| c).collect::<String>(), "h");
+
+        grid.add_entry((0, 6), 'z');
+        let ray: String = grid.ray((0, 0), Direction::Right).map(|(_, &c)| c).take_while(|&c| c != 'd').collect();
+        assert_eq!(ray, "bc");
+        assert_eq!(grid.ray((0, 3), Direction::Right).collect::<Vec<_>>(), vec![((0, 6), &'z')]);
+    }
+
+    #[test]
+    fn first_visible_in_each_direction() {
+        let grid = crate::InputSnake::from_text(ASTEROIDS).grid_snake();
+        assert_eq!(grid.first_visible((2, 0), Direction::Right, |&c| c == '#'), Some(((2, 1), &'#')));
+        assert_eq!(grid.first_visible((2, 0), Direction::Up, |&c| c == '#'), None);
+
+        let visible: Vec<Direction> = grid.all_first_visible((2, 2), |&c| c == '#').into_iter().map(|(d, _, _)| d).collect();
+        assert_eq!(visible, vec![Direction::Left, Direction::Right, Direction::UpRight, Direction::DownRight]);
+    }
+
+    #[test]
+    fn visible_from_reduces_slopes() {
+        let grid = crate::InputSnake::from_text(ASTEROIDS).grid_snake();
+        let counts: Vec<usize> = grid.positions(&'#')
+            .map(|coord| grid.visible_from(coord, |&c| c == '#').len())
+            .collect();
+        assert_eq!(counts, vec![7, 7, 6, 7, 7, 7, 5, 7, 8, 7]);
+        assert!(grid.visible_from((2, 0), |&c| c == '#').iter().all(|&(c, _)| c != (2, 2)));
+    }
+
+    const GALAXIES: &str = r#"
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+"#;
+
+    const ROCKS: &str = r#"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+"#;
+
+    const TILTED_ROCKS: &str = r#"
+OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....
+"#;
+
+    fn galaxy_distances(galaxies: &[Coord]) -> i64 {
+        galaxies.iter()
+            .enumerate()
+            .flat_map(|(i, a)| galaxies[i + 1..].iter().map(move |b| (a.0 - b.0).abs() + (a.1 - b.1).abs()))
+            .sum()
+    }
+
+    #[test]
+    fn inserting_and_removing_lines() {
+        let mut grid = crate::InputSnake::from_text("ab\ncd").grid_snake();
+        grid.insert_row(1, Some('.'));
+        grid.insert_column(0, None);
+        assert_eq!(grid.rows().map(|row| row.map(|(_, c)| c.copied().unwrap_or(' ')).collect::<String>()).collect::<Vec<_>>(),
+            vec![" ab", " ..", " cd"]);
+
+        grid.remove_row(0);
+        grid.remove_column(2);
+        assert_eq!(grid.occupied().collect::<Vec<_>>(), vec![((0, 1), &'.'), ((1, 1), &'c')]);
+        assert_eq!(grid.positions(&'b').count(), 0);
+        assert_eq!(grid.remove_entry((1, 1)), Some('c'));
+        assert_eq!(grid.max_xy(), (1, 1));
+    }
+
+    #[test]
+    fn expanding_empty_lines() {
+        let grid = crate::InputSnake::from_text(GALAXIES).grid_snake();
+        assert_eq!(grid.empty_rows(|&c| c == '.'), vec![3, 7]);
+        assert_eq!(grid.empty_columns(|&c| c == '.'), vec![2, 5, 8]);
+
+        let distances = |factor| {
+            let expansion = grid.expansion(factor, |&c| c == '.');
+            galaxy_distances(&grid.positions(&'#').map(|c| expansion.coord(c)).collect::<Vec<_>>())
+        };
+        assert_eq!(distances(2), 374);
+        assert_eq!(distances(10), 1030);
+        assert_eq!(distances(100), 8410);
+
+        let mut expanded = crate::InputSnake::from_text(GALAXIES).grid_snake();
+        for x in grid.empty_rows(|&c| c == '.').into_iter().rev() {
+            expanded.insert_row(x, Some('.'));
+        }
+        for y in grid.empty_columns(|&c| c == '.').into_iter().rev() {
+            expanded.insert_column(y, Some('.'));
         }
+        assert_eq!(expanded.max_xy(), (11, 12));
+        assert_eq!(galaxy_distances(&expanded.positions(&'#').collect::<Vec<_>>()), 374);
+    }
+
+    #[test]
+    fn tilting_rolls_rocks() {
+        let mut grid = crate::InputSnake::from_text(ROCKS).grid_snake();
+        let load = |grid: &Grid<char>| grid.positions(&'O').map(|(x, _)| 10 - x).sum::<i64>();
+        grid.tilt(Direction::Up, |&c| c == 'O', |&c| c == '#');
+        assert_eq!(load(&grid), 136);
+        let tilted = crate::InputSnake::from_text(TILTED_ROCKS).grid_snake();
+        assert_eq!(grid.positions(&'O').collect::<Vec<_>>(), tilted.positions(&'O').collect::<Vec<_>>());
+        assert_eq!(grid.tilt(Direction::Up, |&c| c == 'O', |&c| c == '#'), 0);
+
+        let mut grid = crate::InputSnake::from_text("O..\n.O#\n...").grid_snake();
+        grid.tilt(Direction::DownRight, |&c| c == 'O', |&c| c == '#');
+        assert_eq!(grid.positions(&'O').collect::<Vec<_>>(), vec![(1, 1), (2, 2)]);
     }
 
-    pub fn read_key(&self) -> Option<Input> {
-        self.input_window.getch()
+    #[test]
+    fn grids_read_and_show_text() {
+        let grid: Grid<char> = ROCKS.parse().unwrap();
+        assert_eq!(grid, crate::InputSnake::from_text(ROCKS).grid_snake());
+        assert_eq!(grid.to_string(), ROCKS.trim());
+        assert_eq!(grid.to_string().parse::<Grid<char>>().unwrap(), grid);
+
+        let mut grid: Grid<char> = "ab\ncd".parse().unwrap();
+        grid.remove_entry((0, 1));
+        grid.add_entry((2, 0), 'e');
+        assert_eq!(grid.to_string(), "a \ncd\ne ");
+    }
+
+    #[test]
+    fn grids_compare_entries_and_bounds() {
+        let mut grid = Grid::new();
+        grid.add_entry((1, 1), 'b');
+        grid.add_entry((0, 0), 'a');
+        let mut other = grid.clone();
+        assert_eq!(other, grid);
+        assert_eq!(other.to_string(), "a \n b");
+        assert_eq!(HashSet::from([grid.clone(), other.clone()]).len(), 1);
+
+        other.add_entry((0, 0), 'c');
+        assert_ne!(other, grid);
+        other.add_entry((0, 0), 'a');
+        other.add_entry((2, 2), 'd');
+        other.remove_entry((2, 2));
+        assert_ne!(other, grid);
+        assert_eq!(Grid::<char>::new(), "".parse().unwrap());
+    }
+
+    #[test]
+    fn seen_grids_find_the_spin_cycle() {
+        let mut grid: Grid<char> = ROCKS.parse().unwrap();
+        let mut seen: HashMap<Grid<char>, usize> = HashMap::new();
+        let mut loads = Vec::new();
+        let spins = 1_000_000_000;
+        while !seen.contains_key(&grid) {
+            seen.insert(grid.clone(), loads.len());
+            loads.push(grid.positions(&'O').map(|(x, _)| 10 - x).sum::<i64>());
+            for &direction in &[Direction::Up, Direction::Left, Direction::Down, Direction::Right] {
+                grid.tilt(direction, |&c| c == 'O', |&c| c == '#');
+            }
+        }
+
+        let start = seen[&grid];
+        let period = loads.len() - start;
+        assert_eq!((start, period), (3, 7));
+        assert_eq!(loads[start + (spins - start) % period], 64);
+    }
+
+    proptest! {
+        #[test]
+        fn opposites_cancel(direction in direction(), coord in coord(), value in -100i64..100) {
+            let there = direction.next_coord_by_value(coord, value);
+            prop_assert_eq!(direction.get_opposite().next_coord_by_value(there, value), coord);
+            prop_assert_eq!(direction.get_opposite().get_opposite(), direction);
+        }
+
+        #[test]
+        fn steps_add_up(direction in direction(), coord in coord(), a in -100i64..100, b in -100i64..100) {
+            let stepped = direction.next_coord_by_value(direction.next_coord_by_value(coord, a), b);
+            prop_assert_eq!(stepped, direction.next_coord_by_value(coord, a + b));
+        }
+
+        #[test]
+        fn bounds_enclose_all_entries(coords in proptest::collection::vec(coord(), 1..50)) {
+            let mut grid = Grid::new();
+            for &coord in &coords {
+                grid.add_entry(coord, coord);
+            }
+
+            let min = (coords.iter().map(|c| c.0).min().unwrap(), coords.iter().map(|c| c.1).min().unwrap());
+            let max = (coords.iter().map(|c| c.0).max().unwrap(), coords.iter().map(|c| c.1).max().unwrap());
+            prop_assert_eq!(grid.min_xy(), min);
+            prop_assert_eq!(grid.max_xy(), max);
+        }
+
+        #[test]
+        fn index_matches_entries(entries in proptest::collection::vec(((-5i64..5, -5i64..5), 0u8..4), 0..60)) {
+            let mut grid = Grid::new();
+            for &(coord, value) in &entries {
+                grid.add_entry(coord, value);
+            }
+
+            for value in 0..4 {
+                let mut expected: Vec<Coord> = grid.locations()
+                    .filter(|&(_, &v)| v == value)
+                    .map(|(c, _)| c)
+                    .collect();
+                expected.sort_unstable();
+                prop_assert_eq!(grid.positions(&value).collect::<Vec<_>>(), expected.clone());
+                prop_assert_eq!(grid.count_where(|&v| v == value), expected.len());
+            }
+            prop_assert_eq!(grid.histogram().values().sum::<usize>(), grid.entries().count());
+        }
+
+        #[test]
+        fn iteration_visits_every_cell_once(origin in (-5i64..5, -5i64..5), rows in 1i64..8, columns in 1i64..8) {
+            let grid = dense_grid(origin, rows, columns);
+
+            let expected: Vec<Coord> = (origin.0..origin.0 + rows)
+                .flat_map(|x| (origin.1..origin.1 + columns).map(move |y| (x, y)))
+                .collect();
+            let entries: Vec<Coord> = grid.iter().copied().collect();
+            let coords: Vec<Coord> = grid.iter().coords().map(|(c, _)| c).collect();
+            prop_assert_eq!(&entries, &expected);
+            prop_assert_eq!(&coords, &expected);
+        }
+
+        #[test]
+        fn occupied_cells_are_the_entries(coords in proptest::collection::hash_set((-10i64..10, -10i64..10), 0..30)) {
+            let mut grid = Grid::new();
+            for &coord in &coords {
+                grid.add_entry(coord, coord);
+            }
+
+            let mut expected: Vec<Coord> = coords.into_iter().collect();
+            expected.sort_unstable();
+            let occupied: Vec<Coord> = grid.occupied().map(|(c, &e)| { assert_eq!(c, e); c }).collect();
+            let cells = grid.cells();
+            prop_assert_eq!(cells.len(), grid.rows().len() * grid.columns().len());
+            prop_assert_eq!(cells.filter(|(_, e)| e.is_some()).count(), expected.len());
+            prop_assert_eq!(&occupied, &expected);
+
+            let mut reversed: Vec<Coord> = grid.occupied().rev().map(|(c, _)| c).collect();
+            reversed.reverse();
+            prop_assert_eq!(&reversed, &expected);
+        }
+
+        #[test]
+        fn tilting_settles(direction in direction(), cells in proptest::collection::vec(0u8..3, 36)) {
+            let mut grid = Grid::new();
+            for (i, &cell) in cells.iter().enumerate() {
+                grid.add_entry((i as i64 / 6, i as i64 % 6), cell);
+            }
+            let histogram: HashMap<u8, usize> = grid.histogram().into_iter().map(|(&v, n)| (v, n)).collect();
+            let blockers: Vec<Coord> = grid.positions(&2).collect();
+
+            grid.tilt(direction, |&v| v == 1, |&v| v == 2);
+            let tilted: HashMap<u8, usize> = grid.histogram().into_iter().map(|(&v, n)| (v, n)).collect();
+            prop_assert_eq!(tilted, histogram);
+            prop_assert_eq!(grid.positions(&2).collect::<Vec<_>>(), blockers);
+            prop_assert_eq!(grid.tilt(direction, |&v| v == 1, |&v| v == 2), 0);
+        }
     }
 }
@@ -0,0 +1,104 @@
+//! Drawing grids with curses, kept apart from the grids so they can be cloned and compared.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use pancurses::Window;
+
+use super::{Coord, Grid, Input};
+
+/// Where grids are drawn, which is stdout until curses is started.
+#[derive(Default)]
+pub struct Screen {
+    window: Option<Window>,
+    input_window: Option<Window>,
+    /// The character last drawn at each coordinate of the curses window.
+    drawn: HashMap<Coord, char>,
+}
+
+pub enum DrawType<'a> {
+    Curses(&'a Window),
+    StdOut,
+}
+
+pub struct ReadProxy {
+    input_window: Window,
+}
+
+impl Screen {
+    pub fn new() -> Self {
+        Screen::default()
+    }
+
+    pub fn init_curses(&mut self) {
+        let stdscr = pancurses::initscr();
+        self.window = Some(stdscr);
+        self.drawn.clear();
+    }
+
+    pub fn init_curses_input(&mut self, x: i32, y: i32) {
+        let input_window = pancurses::newwin(1, 1, x, y);
+        input_window.nodelay(true);
+        input_window.keypad(true);
+        self.input_window = Some(input_window);
+    }
+
+    pub fn end_curses(&mut self) {
+        if self.window.take().is_some() {
+            pancurses::endwin();
+        }
+    }
+
+    /// Draw every cell of the grid within its bounds. With curses only the cells whose character
+    /// changed since they were last drawn are drawn again.
+    pub fn draw<T>(&mut self, grid: &Grid<T>, entry_char: impl Fn(Coord, Option<&T>) -> Option<char>)
+        where T: Debug + Eq + PartialEq + Hash + Clone {
+
+        let window = match &self.window {
+            Some(window) => window,
+            None => return grid.draw(entry_char),
+        };
+
+        for (coord, entry) in grid.cells() {
+            let c = entry_char(coord, entry).unwrap_or(' ');
+            if self.drawn.insert(coord, c) != Some(c) {
+                window.mvaddch(coord.1 as i32, coord.0 as i32, c);
+            }
+        }
+        window.refresh();
+    }
+
+    pub fn draw_type(&self) -> DrawType<'_> {
+        match &self.window {
+            Some(window) => DrawType::Curses(window),
+            None => DrawType::StdOut,
+        }
+    }
+
+    pub fn read_key(&self) -> Option<Input> {
+        self.input_window.as_ref().expect("Input window has been taken").getch()
+    }
+
+    pub fn read_proxy(&mut self) -> ReadProxy {
+        ReadProxy::new(self.input_window.take().expect("Input window was already taken"))
+    }
+}
+
+impl <'a> PartialEq for DrawType<'a> {
+    fn eq(&self, other: &DrawType) -> bool {
+        matches!((self, other), (DrawType::Curses(_), DrawType::Curses(_)) | (DrawType::StdOut, DrawType::StdOut))
+    }
+}
+
+impl ReadProxy {
+    pub fn new(input_window: Window) -> ReadProxy {
+        ReadProxy {
+            input_window,
+        }
+    }
+
+    pub fn read_key(&self) -> Option<Input> {
+        self.input_window.getch()
+    }
+}
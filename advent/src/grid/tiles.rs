@@ -0,0 +1,298 @@
+//! Tiles which send whatever enters them on in other directions, such as pipes, mirrors and
+//! conveyors, and beams following them through a grid.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use super::{Coord, Direction, Grid, ALL_DIRECTIONS};
+
+/// The directions leaving each tile for each direction it is entered moving in.
+///
+/// A tile which is not in the table for a direction cannot be entered moving in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileTable<T>
+    where T: Hash + Eq {
+
+    routes: HashMap<T, HashMap<Direction, Vec<Direction>>>,
+}
+
+/// A beam entering a cell moving in a direction.
+pub type State = (Coord, Direction);
+
+/// Every cell and state reached by the beams from a start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Propagation {
+    /// The cells any beam entered.
+    pub energized: BTreeSet<Coord>,
+    /// The cells of states which lead back to themselves.
+    pub loops: BTreeSet<Coord>,
+    states: HashMap<State, Vec<State>>,
+}
+
+impl <T> Default for TileTable<T>
+    where T: Hash + Eq {
+
+    fn default() -> Self {
+        TileTable::new()
+    }
+}
+
+impl <T> TileTable<T>
+    where T: Hash + Eq {
+
+    pub fn new() -> Self {
+        TileTable {
+            routes: HashMap::new(),
+        }
+    }
+
+    /// Send anything entering the tile moving in the incoming direction out in each outgoing
+    /// direction, which absorbs it when there are none.
+    pub fn with_route(mut self, tile: T, incoming: Direction, outgoing: &[Direction]) -> Self {
+        self.routes.entry(tile)
+            .or_default()
+            .insert(incoming, outgoing.to_vec());
+        self
+    }
+
+    /// Let anything through the tile without turning.
+    pub fn with_pass_through(mut self, tile: T) -> Self {
+        let routes = self.routes.entry(tile).or_default();
+        for &direction in &ALL_DIRECTIONS {
+            routes.insert(direction, vec![direction]);
+        }
+        self
+    }
+
+    /// Send anything entering the tile out in each outgoing direction, whichever way it came.
+    pub fn with_redirect(mut self, tile: T, outgoing: &[Direction]) -> Self {
+        let routes = self.routes.entry(tile).or_default();
+        for &direction in &ALL_DIRECTIONS {
+            routes.insert(direction, outgoing.to_vec());
+        }
+        self
+    }
+
+    /// Connect the sides of the tile in the directions, so it can only be entered through one of
+    /// them and is left through the others.
+    pub fn with_pipe(mut self, tile: T, connections: &[Direction]) -> Self {
+        let routes = self.routes.entry(tile).or_default();
+        for &side in connections {
+            let outgoing = connections.iter().copied().filter(|&d| d != side).collect();
+            routes.insert(side.get_opposite(), outgoing);
+        }
+        self
+    }
+
+    /// Return the directions leaving the tile when entering it moving in the direction, or `None`
+    /// if it cannot be entered that way.
+    pub fn exits(&self, tile: &T, incoming: Direction) -> Option<&[Direction]> {
+        self.routes.get(tile)?
+            .get(&incoming)
+            .map(Vec::as_slice)
+    }
+}
+
+impl Propagation {
+    /// Return every state reached, which is each cell entered with the direction it was entered in.
+    pub fn states(&self) -> impl Iterator<Item = State> + '_ {
+        self.states.keys().copied()
+    }
+
+    pub fn has_loop(&self) -> bool {
+        !self.loops.is_empty()
+    }
+}
+
+/// Send a beam into the start cell moving in the direction, and follow it and every beam it splits
+/// into until they leave the grid, are blocked or reach a state which was already reached.
+pub fn propagate<T>(grid: &Grid<T>, table: &TileTable<T>, start: Coord, direction: Direction) -> Propagation
+    where T: Debug + Eq + Hash + Clone {
+
+    let mut states: HashMap<State, Vec<State>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    if enters(grid, table, (start, direction)) {
+        queue.push_back((start, direction));
+        states.insert((start, direction), Vec::new());
+    }
+
+    while let Some((coord, direction)) = queue.pop_front() {
+        let tile = grid.entry(coord).expect("Entered cells have a tile");
+        let exits = table.exits(tile, direction).expect("Entered tiles have a route");
+        let next: Vec<State> = exits.iter()
+            .map(|&d| (d.next_coord(coord), d))
+            .filter(|&state| enters(grid, table, state))
+            .collect();
+
+        for &state in &next {
+            if let Entry::Vacant(entry) = states.entry(state) {
+                entry.insert(Vec::new());
+                queue.push_back(state);
+            }
+        }
+        states.insert((coord, direction), next);
+    }
+
+    Propagation {
+        energized: states.keys().map(|&(coord, _)| coord).collect(),
+        loops: loops(&states),
+        states,
+    }
+}
+
+fn enters<T>(grid: &Grid<T>, table: &TileTable<T>, (coord, direction): State) -> bool
+    where T: Debug + Eq + Hash + Clone {
+
+    grid.entry(coord).and_then(|tile| table.exits(tile, direction)).is_some()
+}
+
+/// Return the cells of the states which are on a cycle.
+///
+/// Each state counts the states after and before it. A state with none left in either direction
+/// is removed from a worklist, which lowers the counts of its neighbours in turn, so what remains
+/// are the cycles.
+fn loops(states: &HashMap<State, Vec<State>>) -> BTreeSet<Coord> {
+    let mut before: HashMap<State, Vec<State>> = HashMap::new();
+    for (&state, next) in states {
+        for &n in next {
+            before.entry(n).or_default().push(state);
+        }
+    }
+
+    let mut after_count: HashMap<State, usize> = states.iter()
+        .map(|(&state, next)| (state, next.len()))
+        .collect();
+    let mut before_count: HashMap<State, usize> = states.keys()
+        .map(|&state| (state, before.get(&state).map_or(0, Vec::len)))
+        .collect();
+    let mut dead_ends: Vec<State> = states.keys()
+        .copied()
+        .filter(|state| after_count[state] == 0 || before_count[state] == 0)
+        .collect();
+
+    let mut removed: HashSet<State> = HashSet::new();
+    while let Some(state) = dead_ends.pop() {
+        if !removed.insert(state) {
+            continue;
+        }
+        for n in &states[&state] {
+            let count = before_count.get_mut(n).expect("Every next state is a state");
+            *count -= 1;
+            if *count == 0 {
+                dead_ends.push(*n);
+            }
+        }
+        for p in before.get(&state).into_iter().flatten() {
+            let count = after_count.get_mut(p).expect("Every state before is a state");
+            *count -= 1;
+            if *count == 0 {
+                dead_ends.push(*p);
+            }
+        }
+    }
+
+    states.keys()
+        .filter(|state| !removed.contains(state))
+        .map(|&(coord, _)| coord)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InputSnake;
+    use Direction::*;
+
+    const CONTRAPTION: &str = r#"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+"#;
+
+    fn contraption() -> TileTable<char> {
+        TileTable::new()
+            .with_pass_through('.')
+            .with_route('/', Right, &[Up])
+            .with_route('/', Left, &[Down])
+            .with_route('/', Up, &[Right])
+            .with_route('/', Down, &[Left])
+            .with_route('\\', Right, &[Down])
+            .with_route('\\', Left, &[Up])
+            .with_route('\\', Up, &[Left])
+            .with_route('\\', Down, &[Right])
+            .with_route('|', Up, &[Up])
+            .with_route('|', Down, &[Down])
+            .with_route('|', Left, &[Up, Down])
+            .with_route('|', Right, &[Up, Down])
+            .with_route('-', Left, &[Left])
+            .with_route('-', Right, &[Right])
+            .with_route('-', Up, &[Left, Right])
+            .with_route('-', Down, &[Left, Right])
+    }
+
+    #[test]
+    fn beams_energize_the_contraption() {
+        let grid = InputSnake::from_text(CONTRAPTION).grid_snake();
+        let propagation = propagate(&grid, &contraption(), (0, 0), Right);
+        assert_eq!(propagation.energized.len(), 46);
+        assert!(propagation.has_loop());
+
+        let best = (0..10)
+            .map(|y| propagate(&grid, &contraption(), (0, y), Down).energized.len())
+            .max();
+        assert_eq!(best, Some(51));
+    }
+
+    #[test]
+    fn pipes_only_connect_matching_sides() {
+        let table = TileTable::new()
+            .with_pipe('-', &[Left, Right])
+            .with_pipe('7', &[Left, Down])
+            .with_pipe('J', &[Left, Up])
+            .with_pipe('L', &[Up, Right])
+            .with_pipe('F', &[Down, Right]);
+        assert_eq!(table.exits(&'7', Right), Some(&[Down][..]));
+        assert_eq!(table.exits(&'7', Left), None);
+
+        let grid = InputSnake::from_text("F-7\nL-J\n..-").grid_snake();
+        let propagation = propagate(&grid, &table, (0, 1), Right);
+        assert_eq!(propagation.energized.len(), 6);
+        assert_eq!(propagation.loops, propagation.energized);
+        assert!(!propagation.energized.contains(&(2, 2)));
+    }
+
+    #[test]
+    fn conveyors_without_a_loop() {
+        let table = TileTable::new()
+            .with_redirect('>', &[Right])
+            .with_redirect('v', &[Down])
+            .with_route('#', Down, &[]);
+        let grid = InputSnake::from_text(">>v\n.<#").grid_snake();
+        let propagation = propagate(&grid, &table, (0, 0), Up);
+        assert_eq!(propagation.energized, BTreeSet::from([(0, 0), (0, 1), (0, 2), (1, 2)]));
+        assert!(!propagation.has_loop());
+        assert_eq!(propagation.states().count(), 4);
+    }
+
+    #[test]
+    fn loops_leave_out_the_chain_leading_into_them() {
+        let table = TileTable::new()
+            .with_redirect('>', &[Right])
+            .with_redirect('<', &[Left])
+            .with_redirect('^', &[Up])
+            .with_redirect('v', &[Down]);
+        let grid = InputSnake::from_text(">>>>>>v<\n......>^").grid_snake();
+        let propagation = propagate(&grid, &table, (0, 0), Right);
+        assert_eq!(propagation.energized.len(), 10);
+        assert_eq!(propagation.loops, BTreeSet::from([(0, 6), (0, 7), (1, 6), (1, 7)]));
+    }
+}
@@ -1,24 +1,112 @@
 use regex::{CaptureMatches, Regex};
-use nom::{Parser, error::ParseError};
 
-use std::{io::{BufReader, BufRead}, fs::File};
+use std::{io::{BufReader, BufRead, Read}, fs::File};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::marker::PhantomData;
+use std::fmt;
 
+pub use advent_derive::{FromGridChar, FromRegex};
+
+// lets the derives, which refer to `::advent`, be used inside this crate
+extern crate self as advent;
+
+pub mod cards;
+pub mod cube;
+pub mod day;
+pub mod fetch;
 pub mod grid;
 pub mod map;
+pub mod parse;
+pub mod point;
+pub mod puzzle;
+pub mod registry;
 pub mod snake;
+pub mod submit;
+pub mod timing;
+
+/// A type which can be created from the matches of a regex on a line of input.
+///
+/// Usually derived with `#[derive(FromRegex)]` and a `#[regex("...")]` attribute.
+pub trait FromRegex: Sized {
+    /// The regex matched against each line.
+    fn regex() -> &'static str;
+
+    /// Return the regex compiled once, which the derive keeps in a static.
+    fn compiled_regex() -> &'static Regex;
+
+    fn from(capture_matches: CaptureMatches) -> Result<Self, FromRegexError>;
+
+    /// Create the type by matching its regex against the line.
+    fn from_line(line: &str) -> Result<Self, FromRegexError> {
+        Self::from(Self::compiled_regex().captures_iter(line))
+    }
+}
+
+/// The reason captures could not be converted by `FromRegex`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromRegexError {
+    /// The regex did not match the line.
+    NoMatch,
+    /// A required capture group did not participate in the match.
+    MissingGroup(&'static str),
+    /// A captured value could not be parsed into its field.
+    InvalidValue { group: &'static str, value: String },
+}
+
+/// A `FromRegexError` for a line (numbered from 1) of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegexLineError {
+    pub line_number: usize,
+    pub line: String,
+    pub error: FromRegexError,
+}
+
+/// A tile of a grid which is read from a single character.
+///
+/// Usually derived for an enum with `#[derive(FromGridChar)]` and a `#[tile('#')]` attribute on
+/// each variant, which is `#[tile('S', marker = "start")]` for a tile marking a position.
+pub trait FromGridChar: Sized {
+    fn from_grid_char(c: char) -> Option<Self>;
+
+    /// Return the character the tile is read from.
+    fn to_grid_char(&self) -> char;
+
+    /// Return the name of the position the tile marks, such as `start`.
+    fn marker(&self) -> Option<&'static str> {
+        None
+    }
+}
 
-pub trait FromRegex {
-    fn from(capture_matches: CaptureMatches) -> Self;
+/// A grid of tiles with the coordinates of every marker tile.
+pub struct TileGrid<T> {
+    pub grid: grid::Grid<T>,
+    pub markers: HashMap<&'static str, Vec<grid::Coord>>,
+}
+
+/// The characters of a grid which are not tiles, with their coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownTileError {
+    pub tiles: Vec<(grid::Coord, char)>,
 }
 
 pub struct InputSnake {
-    path: PathBuf,
+    source: Source,
 }
 
-pub struct GroupIterator {
-    line_iter: Box<dyn Iterator<Item = String>>,
+/// Where an `InputSnake` reads its input from.
+enum Source {
+    Path(PathBuf),
+    Text(String),
+}
+
+pub struct GroupIterator<I = Box<dyn Iterator<Item = String>>> {
+    line_iter: I,
+}
+
+/// The whole input read once, which lends out lines, groups and grids borrowed from it.
+pub struct InputBuffer {
+    text: String,
 }
 
 pub struct NomOutput<P, O> {
@@ -44,16 +132,20 @@ impl <P, O> NomOutput<P, O>
    }
 }
 
-impl GroupIterator {
-    pub fn new(line_iter: Box<dyn Iterator<Item = String>>) -> Self {
+impl <I> GroupIterator<I> {
+    pub fn new(line_iter: I) -> Self {
         GroupIterator {
             line_iter,
         }
     }
 }
 
-impl Iterator for GroupIterator {
-    type Item = Vec<String>;
+impl <I> Iterator for GroupIterator<I>
+    where
+        I: Iterator,
+        I::Item: AsRef<str> {
+
+    type Item = Vec<I::Item>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let mut group = Vec::new();
@@ -69,7 +161,7 @@ impl Iterator for GroupIterator {
                 Some(line) => line,
             };
 
-            if line.is_empty() {
+            if line.as_ref().is_empty() {
                 return Some(group);
             }
 
@@ -80,19 +172,53 @@ impl Iterator for GroupIterator {
 
 impl InputSnake {
     pub fn new<P: AsRef<Path>>(path: P) -> Self {
-        return InputSnake {
-            path: path.as_ref().to_path_buf()
+        InputSnake {
+            source: Source::Path(path.as_ref().to_path_buf()),
+        }
+    }
+
+    /// Create a snake over in-memory text.
+    ///
+    /// A single leading newline is ignored so examples can start on the line after `r#"`.
+    pub fn from_text(text: &str) -> Self {
+        let text = text.strip_prefix('\n').unwrap_or(text);
+        InputSnake {
+            source: Source::Text(text.to_string()),
+        }
+    }
+
+    /// Create a snake over everything read from the reader.
+    pub fn from_reader<R: Read>(mut reader: R) -> Self {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).expect("Unable to read input");
+        InputSnake {
+            source: Source::Text(text),
         }
     }
 
+    /// Create a snake over everything piped to stdin.
+    pub fn stdin() -> Self {
+        InputSnake::from_reader(std::io::stdin())
+    }
+
     /// 🐍
     pub fn snake(&self) -> Box<dyn Iterator<Item = String>> {
-        let f = File::open(&self.path).expect("Unable to open file at path");
-        let f = BufReader::new(f);
-        Box::new(f.lines().map(|l| l.unwrap()))
+        match &self.source {
+            Source::Path(path) => {
+                let f = File::open(path).expect("Unable to open file at path");
+                let f = BufReader::new(f);
+                Box::new(f.lines().map(|l| l.unwrap()))
+            }
+            Source::Text(text) => {
+                let lines: Vec<String> = text.lines().map(String::from).collect();
+                Box::new(lines.into_iter())
+            }
+        }
     }
 
     /// 🍕🐍
+    ///
+    /// The legacy path, which copies every line into a `NomOutput`; prefer `InputBuffer::nom_lines`.
     pub fn nom_snake<P, O>(&self, parser: P) -> Box<dyn Iterator<Item = NomOutput<P, O>>>
     where
         P: for<'a> nom::Parser<&'a str, O, nom::error::Error<&'a str>> + 'static + Copy {
@@ -106,18 +232,17 @@ impl InputSnake {
     }
 
     /// ✱🐍
-    pub fn regex_snake<T: FromRegex>(&self, regex_str: &'static str) -> Box<dyn Iterator<Item = T>> {
-        let regex = Regex::new(regex_str).unwrap();
+    pub fn regex_snake<T: FromRegex + 'static>(&self) -> Box<dyn Iterator<Item = Result<T, RegexLineError>>> {
         let items = self.snake()
-            .map(move |s| FromRegex::from(regex.captures_iter(&s)));
+            .enumerate()
+            .map(|(i, line)| T::from_line(&line)
+                .map_err(|error| RegexLineError { line_number: i + 1, line, error }));
         Box::new(items)
     }
 
     /// (🐍)
     pub fn group_snake(&self) -> Box<dyn Iterator<Item = Vec<String>>> {
-        Box::new(GroupIterator {
-            line_iter: Box::new(self.snake())
-        })
+        Box::new(GroupIterator::new(self.snake()))
     }
 
     /// 🐍🐍
@@ -132,10 +257,342 @@ impl InputSnake {
         grid
     }
 
+    /// 🧱🐍
+    ///
+    /// Read a grid of tiles, using the same coordinates as `grid_snake`.
+    pub fn typed_grid_snake<T>(&self) -> Result<TileGrid<T>, UnknownTileError>
+    where
+        T: FromGridChar + fmt::Debug + Eq + std::hash::Hash + Clone {
+
+        let mut tile_grid = TileGrid { grid: grid::Grid::new(), markers: HashMap::new() };
+        let mut unknown = Vec::new();
+        for (coord, c) in self.grid_snake().occupied() {
+            match T::from_grid_char(*c) {
+                Some(tile) => {
+                    if let Some(marker) = tile.marker() {
+                        tile_grid.markers.entry(marker).or_insert_with(Vec::new).push(coord);
+                    }
+                    tile_grid.grid.add_entry(coord, tile);
+                },
+                None => unknown.push((coord, *c)),
+            }
+        }
+
+        if unknown.is_empty() {
+            Ok(tile_grid)
+        } else {
+            Err(UnknownTileError { tiles: unknown })
+        }
+    }
+
+    /// 📦🐍
+    ///
+    /// Read the input once into a buffer which lends out borrowed lines instead of allocating them.
+    pub fn buffer(&self) -> InputBuffer {
+        InputBuffer {
+            text: match &self.source {
+                Source::Path(path) => std::fs::read_to_string(path).expect("Unable to open file at path"),
+                Source::Text(text) => text.clone(),
+            },
+        }
+    }
+
     /// ❌🐍
     pub fn no_snake(&self) -> String {
-        std::fs::read_to_string(&self.path).expect("Unable to open file at path")
-            .trim()
-            .to_string()
+        match &self.source {
+            Source::Path(path) => std::fs::read_to_string(path).expect("Unable to open file at path")
+                .trim()
+                .to_string(),
+            Source::Text(text) => text.trim().to_string(),
+        }
+    }
+}
+
+impl InputBuffer {
+    /// Return the whole input, trimmed like `no_snake`.
+    pub fn text(&self) -> &str {
+        self.text.trim()
+    }
+
+    /// Return each line of the input.
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.text.lines()
+    }
+
+    /// Return the groups of lines separated by blank lines.
+    pub fn groups(&self) -> GroupIterator<std::str::Lines<'_>> {
+        GroupIterator::new(self.lines())
+    }
+
+    /// Return a grid of single-character slices, using the same coordinates as `grid_snake`.
+    pub fn grid(&self) -> grid::Grid<&str> {
+        let mut grid = grid::Grid::new();
+        for (x, line) in self.lines().enumerate() {
+            let cells = line.char_indices()
+                .map(|(i, c)| &line[i..i + c.len_utf8()]);
+            for (y, cell) in cells.enumerate() {
+                grid.add_entry((x as i64, y as i64), cell);
+            }
+        }
+        grid
+    }
+
+    /// Parse each whole line, returning outputs which may borrow from the buffer.
+    ///
+    /// Errors carry the line number (starting at 1) of the line which failed.
+    pub fn nom_lines<'a, P, O>(&'a self, mut parser: P) -> impl Iterator<Item = Result<O, parse::ParseError>> + 'a
+    where
+        P: nom::Parser<&'a str, O, nom::error::Error<&'a str>> + 'a {
+
+        self.lines()
+            .enumerate()
+            .map(move |(i, line)| parse::parse_all(|line| parser.parse(line), line)
+                .map_err(|error| parse::ParseError { line: i + 1, ..error }))
+    }
+
+    /// Parse the whole input with `parse::parse_all`, returning output which may borrow from the buffer.
+    pub fn parse<'a, P, O>(&'a self, parser: P) -> Result<O, parse::ParseError>
+    where
+        P: nom::Parser<&'a str, O, nom::error::Error<&'a str>> {
+
+        parse::parse_all(parser, self.text())
+    }
+}
+
+impl <T> TileGrid<T> {
+    /// Return the coordinate of the marker, if exactly one tile has it.
+    pub fn marker(&self, name: &str) -> Option<grid::Coord> {
+        match self.markers.get(name).map(Vec::as_slice) {
+            Some(&[coord]) => Some(coord),
+            _ => None,
+        }
+    }
+}
+
+/// Every character is a tile of itself, which is how `grid_snake` reads a grid.
+impl FromGridChar for char {
+    fn from_grid_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_grid_char(&self) -> char {
+        *self
+    }
+}
+
+impl fmt::Display for FromRegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromRegexError::NoMatch => write!(f, "regex did not match"),
+            FromRegexError::MissingGroup(group) => write!(f, "missing capture group `{}`", group),
+            FromRegexError::InvalidValue { group, value } => write!(f, "invalid value {:?} for capture group `{}`", value, group),
+        }
+    }
+}
+
+impl std::error::Error for FromRegexError {}
+
+impl fmt::Display for RegexLineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}: {:?}", self.line_number, self.error, self.line)
+    }
+}
+
+impl std::error::Error for RegexLineError {}
+
+impl fmt::Display for UnknownTileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tiles: Vec<String> = self.tiles.iter()
+            .map(|(coord, c)| format!("{:?} at {:?}", c, coord))
+            .collect();
+        write!(f, "unknown tiles: {}", tiles.join(", "))
+    }
+}
+
+impl std::error::Error for UnknownTileError {}
+
+/// Support for code generated by `#[derive(FromRegex)]`.
+#[doc(hidden)]
+pub mod __private {
+    use super::FromRegexError;
+    use std::str::FromStr;
+
+    pub use regex::{CaptureMatches, Captures, Regex};
+
+    fn parse<T: FromStr>(group: &'static str, value: &str) -> Result<T, FromRegexError> {
+        value.parse().map_err(|_| FromRegexError::InvalidValue { group, value: value.to_string() })
+    }
+
+    pub fn required<T: FromStr>(captures: &[Captures], group: &'static str) -> Result<T, FromRegexError> {
+        optional(captures, group)?.ok_or(FromRegexError::MissingGroup(group))
+    }
+
+    pub fn optional<T: FromStr>(captures: &[Captures], group: &'static str) -> Result<Option<T>, FromRegexError> {
+        captures.first()
+            .and_then(|c| c.name(group))
+            .map(|m| parse(group, m.as_str()))
+            .transpose()
+    }
+
+    pub fn repeated<T: FromStr>(captures: &[Captures], group: &'static str) -> Result<Vec<T>, FromRegexError> {
+        captures.iter()
+            .filter_map(|c| c.name(group))
+            .map(|m| parse(group, m.as_str()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+ab
+cd
+
+ef
+"#;
+
+    #[test]
+    fn from_text_ignores_leading_newline() {
+        let input = InputSnake::from_text(EXAMPLE);
+        assert_eq!(input.snake().collect::<Vec<_>>(), vec!["ab", "cd", "", "ef"]);
+        assert_eq!(input.no_snake(), "ab\ncd\n\nef");
+    }
+
+    #[test]
+    fn from_text_supports_every_snake() {
+        let input = InputSnake::from_text(EXAMPLE);
+        assert_eq!(input.group_snake().collect::<Vec<_>>(), vec![vec!["ab", "cd"], vec!["ef"]]);
+        assert_eq!(input.grid_snake().entry((1, 1)), Some(&'d'));
+
+        fn word_length(input: &str) -> nom::IResult<&str, usize> {
+            nom::combinator::map(nom::character::complete::alpha0, str::len)(input)
+        }
+        let lengths: Vec<usize> = input.nom_snake(word_length)
+            .map(|mut output| output.parse())
+            .collect();
+        assert_eq!(lengths, vec![2, 2, 0, 2]);
+    }
+
+    #[test]
+    fn buffer_lends_borrowed_lines() {
+        let buffer = InputSnake::from_text(EXAMPLE).buffer();
+        assert_eq!(buffer.lines().collect::<Vec<&str>>(), vec!["ab", "cd", "", "ef"]);
+        assert_eq!(buffer.groups().collect::<Vec<_>>(), vec![vec!["ab", "cd"], vec!["ef"]]);
+        assert_eq!(buffer.grid().entry((1, 0)), Some(&"c"));
+    }
+
+    #[test]
+    fn buffer_parsers_return_borrowed_output() {
+        let buffer = InputSnake::from_text("a-b\ncd-e").buffer();
+        let tag = String::from("-");
+        let pairs: Vec<(&str, &str)> = buffer.nom_lines(nom::sequence::separated_pair(
+                nom::character::complete::alpha1,
+                nom::bytes::complete::tag(tag.as_str()),
+                nom::character::complete::alpha1))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(pairs, vec![("a", "b"), ("cd", "e")]);
+
+        let buffer = InputSnake::from_text("ab
+cd-
+ef").buffer();
+        let errors: Vec<parse::ParseError> = buffer.nom_lines(nom::character::complete::alpha1)
+            .filter_map(Result::err)
+            .collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].line, errors[0].column), (2, 3));
+
+        let words = buffer.parse(parse::lines(nom::character::complete::alpha1));
+        assert!(words.is_err());
+    }
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, FromGridChar)]
+    enum Tile {
+        #[tile('.')]
+        Open,
+        #[tile('#')]
+        Wall,
+        #[tile('S', marker = "start")]
+        Start,
+    }
+
+    #[test]
+    fn from_grid_char_derive_maps_tiles_and_markers() {
+        assert_eq!(Tile::from_grid_char('#'), Some(Tile::Wall));
+        assert_eq!(Tile::from_grid_char('x'), None);
+        assert_eq!(Tile::Start.to_grid_char(), 'S');
+        assert_eq!(Tile::Start.marker(), Some("start"));
+        assert_eq!(Tile::Open.marker(), None);
+    }
+
+    #[test]
+    fn typed_grid_snake_finds_markers() {
+        let tiles = InputSnake::from_text("#.#\n.S.\n").typed_grid_snake::<Tile>().unwrap();
+        assert_eq!(tiles.grid.entry((0, 1)), Some(&Tile::Open));
+        assert_eq!(tiles.marker("start"), Some((1, 1)));
+        assert_eq!(tiles.marker("end"), None);
+
+        let tiles = InputSnake::from_text("S.S").typed_grid_snake::<Tile>().unwrap();
+        assert_eq!(tiles.markers["start"], vec![(0, 0), (0, 2)]);
+        assert_eq!(tiles.marker("start"), None);
+    }
+
+    #[test]
+    fn typed_grid_snake_reports_unknown_tiles() {
+        let error = InputSnake::from_text("#.x\n?S.\n").typed_grid_snake::<Tile>().err().unwrap();
+        assert_eq!(error.tiles, vec![((0, 2), 'x'), ((1, 0), '?')]);
+        assert_eq!(error.to_string(), "unknown tiles: 'x' at (0, 2), '?' at (1, 0)");
+    }
+
+    #[test]
+    fn typed_grids_parse_and_display() {
+        let grid: grid::Grid<Tile> = "#.#\n.S.".parse().unwrap();
+        assert_eq!(grid, InputSnake::from_text("#.#\n.S.").typed_grid_snake::<Tile>().unwrap().grid);
+        assert_eq!(grid.to_string(), "#.#\n.S.");
+        assert_eq!("#x".parse::<grid::Grid<Tile>>().err().unwrap().tiles, vec![((0, 1), 'x')]);
+    }
+
+    #[derive(Debug, PartialEq, FromRegex)]
+    #[regex(r"^(?P<name>[a-z]+)(?: (?P<count>\d+))?|#(?P<tags>\w+)")]
+    struct Item {
+        name: String,
+        count: Option<u8>,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn from_regex_fills_each_kind_of_field() {
+        let item = Item::from_line("apple 3 #red #ripe").unwrap();
+        assert_eq!(item, Item { name: "apple".to_string(), count: Some(3), tags: vec!["red".to_string(), "ripe".to_string()] });
+
+        let item = Item::from_line("pear").unwrap();
+        assert_eq!(item, Item { name: "pear".to_string(), count: None, tags: Vec::new() });
+        assert!(std::ptr::eq(Item::compiled_regex(), Item::compiled_regex()));
+    }
+
+    #[test]
+    fn from_regex_reports_errors() {
+        assert_eq!(Item::from_line("APPLE"), Err(FromRegexError::NoMatch));
+        assert_eq!(Item::from_line("#red"), Err(FromRegexError::MissingGroup("name")));
+        assert_eq!(Item::from_line("apple 300"), Err(FromRegexError::InvalidValue { group: "count", value: "300".to_string() }));
+    }
+
+    #[test]
+    fn regex_snake_numbers_lines_from_one() {
+        let items: Vec<Result<Item, RegexLineError>> = InputSnake::from_text("apple 1\n#red\npear").regex_snake().collect();
+        assert!(items[0].is_ok() && items[2].is_ok());
+
+        let error = items[1].as_ref().unwrap_err();
+        assert_eq!(error.line_number, 2);
+        assert_eq!(error.line, "#red");
+        assert_eq!(error.to_string(), "line 2: missing capture group `name`: \"#red\"");
+    }
+
+    #[test]
+    fn from_reader_matches_from_text() {
+        let input = InputSnake::from_reader("1\n-2\n3\n".as_bytes());
+        assert_eq!(input.int_snake().collect::<Vec<_>>(), vec![1, -2, 3]);
     }
 }
@@ -1,7 +1,10 @@
+use std::collections::HashMap;
 use std::fmt;
 
-use crate::grid;
-use crate::grid::Coord;
+use crate::grid::{Coord, Grid};
+use crate::point::{Heading, Point};
+
+pub mod turtle;
 
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -19,12 +22,23 @@ pub enum Rotation {
 }
 
 /// A location on a grid map which contains the cardinal directions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Location {
     starting_location: Coord,
     location: Coord,
 }
 
+/// A location which records every unit cell it passes through.
+#[derive(Debug, Clone)]
+pub struct TrackedLocation {
+    location: Location,
+    path: Vec<Point>,
+    visits: HashMap<Point, usize>,
+    first_revisit: Option<Point>,
+}
+
 /// The point-of-view on a grid map which contains the cardinal directions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Viewpoint {
     starting_direction: Direction,
     direction: Direction,
@@ -89,15 +103,11 @@ impl Location {
     }
 
     /// Move the location in the given direction with the given value.
+    ///
+    /// A location is (east, south), so it is the column and row of a grid `Point`.
     pub fn movement(&mut self, direction: Direction, value: i64) {
-        let current_location = self.location;
-        let next_location = match direction {
-            Direction::North => grid::Direction::Up.next_coord_by_value(current_location, value),
-            Direction::East => grid::Direction::Right.next_coord_by_value(current_location, value),
-            Direction::South => grid::Direction::Down.next_coord_by_value(current_location, value),
-            Direction::West => grid::Direction::Left.next_coord_by_value(current_location, value),
-        };
-        self.location = next_location;
+        let step = direction.unit() * value;
+        self.location = (self.location.0 + step.col(), self.location.1 + step.row());
     }
 
     /// Rotate this location assuming its relative to (0, 0) 
@@ -112,6 +122,16 @@ impl Location {
         self.location = new_location;
     }
 
+    /// Return the location as a grid point, whose row grows south and column grows east.
+    pub fn point(&self) -> Point {
+        Point::new(self.location.1, self.location.0)
+    }
+
+    /// Create a new location starting at (0,0) which records its path.
+    pub fn tracked() -> TrackedLocation {
+        TrackedLocation::new(Location::new())
+    }
+
     /// Return the manhattan distance of the location from the starting position.
     pub fn manhattan_distance(&self) -> i64 {
         (self.location.0 - self.starting_location.0).abs() + (self.location.1 - self.starting_location.1).abs()
@@ -121,14 +141,97 @@ impl Location {
     fn coordinate_value(direction: Direction, value: i64) -> i64 {
         let abs = value.abs();
         match direction {
-            Direction::North => -1 * abs,
+            Direction::North => -abs,
             Direction::East => abs,
             Direction::South => abs,
-            Direction::West => -1 * abs,
+            Direction::West => -abs,
         }
     }
 }
 
+impl Default for Location {
+    fn default() -> Self {
+        Location::new()
+    }
+}
+
+impl TrackedLocation {
+    pub fn new(location: Location) -> Self {
+        let start = location.point();
+        TrackedLocation {
+            location,
+            path: vec![start],
+            visits: HashMap::from([(start, 1)]),
+            first_revisit: None,
+        }
+    }
+
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    /// Move the location one cell at a time in the given direction, recording each cell.
+    pub fn movement(&mut self, direction: Direction, value: i64) {
+        let (direction, steps) = if value < 0 {
+            (direction.opposite(), -value)
+        } else {
+            (direction, value)
+        };
+
+        for _ in 0..steps {
+            self.location.movement(direction, 1);
+            let point = self.location.point();
+            self.path.push(point);
+
+            let visits = self.visits.entry(point).or_insert(0);
+            *visits += 1;
+            if *visits == 2 && self.first_revisit.is_none() {
+                self.first_revisit = Some(point);
+            }
+        }
+    }
+
+    /// Return every cell passed through in order, starting with the starting cell.
+    pub fn path(&self) -> &[Point] {
+        &self.path
+    }
+
+    /// Return the first cell which was passed through a second time.
+    pub fn first_revisit(&self) -> Option<Point> {
+        self.first_revisit
+    }
+
+    /// Return how many times the cell was passed through.
+    pub fn visits(&self, point: Point) -> usize {
+        self.visits.get(&point).copied().unwrap_or(0)
+    }
+
+    /// Return the number of different cells passed through.
+    pub fn visited(&self) -> usize {
+        self.visits.len()
+    }
+
+    /// Return the smallest and largest row and column passed through.
+    pub fn bounds(&self) -> (Point, Point) {
+        let rows = self.path.iter().map(|p| p.row());
+        let cols = self.path.iter().map(|p| p.col());
+        let min = Point::new(rows.clone().min().unwrap_or(0), cols.clone().min().unwrap_or(0));
+        let max = Point::new(rows.max().unwrap_or(0), cols.max().unwrap_or(0));
+        (min, max)
+    }
+
+    /// Draw the path as `#`, with `S` at the start and `@` at the current location.
+    pub fn to_grid(&self) -> Grid<char> {
+        let mut grid = Grid::new();
+        for &point in &self.path {
+            grid.add_entry(point.0, '#');
+        }
+        grid.add_entry(self.path[0].0, 'S');
+        grid.add_entry(self.location.point().0, '@');
+        grid
+    }
+}
+
 impl Viewpoint {
     /// Create a new location usin gthe starting direction.
     pub fn new(starting_direction: Direction) -> Self {
@@ -143,29 +246,24 @@ impl Viewpoint {
         self.direction
     }
 
+    /// Return the direction this point-of-view started facing.
+    pub fn starting_direction(&self) -> Direction {
+        self.starting_direction
+    }
+
     /// Rotate the point-of-view in the given direction with the given value.
     pub fn rotate(&mut self, rotation: Rotation, value: i64) {
         self.direction = Viewpoint::rotate_direction(self.direction, rotation, value);
     }
 
-    /// Rotate the given direction, returning the resulting direction.
+    /// Rotate the given direction by any multiple of 90 degrees, returning the resulting direction.
     pub fn rotate_direction(direction: Direction, rotation: Rotation, value: i64) -> Direction {
-        match (direction, rotation, value) {
-            (Direction::North, Rotation::Left, 90)  => Direction::West,
-            (Direction::North, Rotation::Left, 180) => Direction::South,
-            (Direction::North, Rotation::Left, 270) => Direction::East,
-            (Direction::East,  Rotation::Left, 90)  => Direction::North,
-            (Direction::East,  Rotation::Left, 180) => Direction::West,
-            (Direction::East,  Rotation::Left, 270) => Direction::South,
-            (Direction::South, Rotation::Left, 90)  => Direction::East,
-            (Direction::South, Rotation::Left, 180) => Direction::North,
-            (Direction::South, Rotation::Left, 270) => Direction::West,
-            (Direction::West,  Rotation::Left, 90)  => Direction::South,
-            (Direction::West,  Rotation::Left, 180) => Direction::East,
-            (Direction::West,  Rotation::Left, 270) => Direction::North,
-            (_, Rotation::Right, v) => Viewpoint::rotate_direction(direction, Rotation::Left, 360 - v),
-            _ => panic!("Unhandled tuple: {} {} {}", direction, rotation, value),
-        }
+        assert!(value % 90 == 0, "Unhandled rotation angle: {}", value);
+        let quarters = value.rem_euclid(360) / 90;
+        (0..quarters).fold(direction, |d, _| match rotation {
+            Rotation::Left => d.turn_left(),
+            Rotation::Right => d.turn_right(),
+        })
     }
 }
 
@@ -196,4 +294,144 @@ impl fmt::Display for Location {
         let ((horizontal_dir, horizontal_val), (vertical_dir, vertical_val)) = self.location();
         write!(f, "({} {}, {} {})", horizontal_dir, horizontal_val, vertical_dir, vertical_val)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        proptest::sample::select(vec![Direction::North, Direction::East, Direction::South, Direction::West])
+    }
+
+    fn angle() -> impl Strategy<Value = i64> {
+        proptest::sample::select(vec![90, 180, 270])
+    }
+
+    fn movements() -> impl Strategy<Value = Vec<(Direction, i64)>> {
+        proptest::collection::vec((direction(), 0i64..100), 0..20)
+    }
+
+    #[test]
+    fn rotate_direction_turns() {
+        assert_eq!(Viewpoint::rotate_direction(Direction::North, Rotation::Right, 90), Direction::East);
+        assert_eq!(Viewpoint::rotate_direction(Direction::North, Rotation::Left, 90), Direction::West);
+        assert_eq!(Viewpoint::rotate_direction(Direction::East, Rotation::Right, 180), Direction::West);
+        assert_eq!(Viewpoint::rotate_direction(Direction::East, Rotation::Right, 0), Direction::East);
+        assert_eq!(Viewpoint::rotate_direction(Direction::East, Rotation::Left, 360), Direction::East);
+        assert_eq!(Viewpoint::rotate_direction(Direction::East, Rotation::Left, 450), Direction::North);
+        assert_eq!(Viewpoint::rotate_direction(Direction::East, Rotation::Left, -90), Direction::South);
+    }
+
+    #[test]
+    fn location_is_relative_to_the_start() {
+        let location = Location::new_at_location(&[(Direction::North, 3), (Direction::West, 5), (Direction::South, 1)]);
+        assert_eq!(location.location(), ((Direction::West, 5), (Direction::North, 2)));
+        assert_eq!(location.manhattan_distance(), 7);
+        assert_eq!(location.to_string(), "(W 5, N 2)");
+
+        let mut waypoint = Location::new_at_location(&[(Direction::East, 10), (Direction::North, 4)]);
+        waypoint.relative_rotate(Rotation::Right, 90);
+        assert_eq!(waypoint.location(), ((Direction::East, 4), (Direction::South, 10)));
+    }
+
+    #[test]
+    fn movements_follow_the_compass() {
+        let mut location = Location::new();
+        location.movement(Direction::North, 3);
+        assert_eq!(location.location(), ((Direction::East, 0), (Direction::North, 3)));
+
+        location.movement(Direction::East, 2);
+        assert_eq!(location.location(), ((Direction::East, 2), (Direction::North, 3)));
+
+        location.movement(Direction::South, 5);
+        location.movement(Direction::West, 4);
+        assert_eq!(location.location(), ((Direction::West, 2), (Direction::South, 2)));
+    }
+
+    #[test]
+    fn tracked_location_finds_first_revisit() {
+        let mut viewpoint = Viewpoint::new(Direction::North);
+        let mut tracked = Location::tracked();
+        for value in [8, 4, 4, 8] {
+            viewpoint.rotate(Rotation::Right, 90);
+            tracked.movement(viewpoint.direction(), value);
+        }
+
+        let revisit = tracked.first_revisit().unwrap();
+        assert_eq!(revisit.manhattan(Point::ORIGIN), 4);
+        assert_eq!(tracked.visits(revisit), 2);
+        assert_eq!(tracked.path().len(), 25);
+        assert_eq!(tracked.visited(), 24);
+        assert_eq!(tracked.bounds(), (Point::new(-4, 0), Point::new(4, 8)));
+        assert_eq!(tracked.location().manhattan_distance(), 8);
+    }
+
+    #[test]
+    fn tracked_location_to_grid() {
+        let mut tracked = Location::tracked();
+        tracked.movement(Direction::East, 2);
+        tracked.movement(Direction::South, 1);
+        tracked.movement(Direction::West, -1);
+
+        let grid = tracked.to_grid();
+        assert_eq!(grid.entry((0, 0)), Some(&'S'));
+        assert_eq!(grid.entry((0, 2)), Some(&'#'));
+        assert_eq!(grid.entry((1, 3)), Some(&'@'));
+        assert_eq!(grid.entry((1, 0)), None);
+    }
+
+    proptest! {
+        #[test]
+        fn rotations_undo(direction in direction(), angle in angle()) {
+            let turned = Viewpoint::rotate_direction(direction, Rotation::Left, angle);
+            prop_assert_eq!(Viewpoint::rotate_direction(turned, Rotation::Right, angle), direction);
+        }
+
+        #[test]
+        fn rotations_compose(direction in direction(), a in angle(), b in angle()) {
+            prop_assume!((a + b) % 360 != 0);
+            let twice = Viewpoint::rotate_direction(Viewpoint::rotate_direction(direction, Rotation::Left, a), Rotation::Left, b);
+            prop_assert_eq!(twice, Viewpoint::rotate_direction(direction, Rotation::Left, (a + b) % 360));
+        }
+
+        #[test]
+        fn opposite_movements_cancel(movements in movements()) {
+            let mut location = Location::new_at_location(&movements);
+            for &(direction, value) in &movements {
+                location.movement(Viewpoint::rotate_direction(direction, Rotation::Left, 180), value);
+            }
+            prop_assert_eq!(location.manhattan_distance(), 0);
+        }
+
+        #[test]
+        fn tracked_location_passes_every_cell(movements in movements()) {
+            let mut tracked = Location::tracked();
+            for &(direction, value) in &movements {
+                tracked.movement(direction, value);
+            }
+
+            prop_assert_eq!(tracked.location(), &Location::new_at_location(&movements));
+            prop_assert_eq!(tracked.path().len() as i64, 1 + movements.iter().map(|m| m.1).sum::<i64>());
+            for step in tracked.path().windows(2) {
+                prop_assert_eq!(step[0].manhattan(step[1]), 1);
+            }
+        }
+
+        #[test]
+        fn relative_rotation_keeps_distance(movements in movements(), rotation in prop_oneof![Just(Rotation::Left), Just(Rotation::Right)], angle in angle()) {
+            let mut location = Location::new_at_location(&movements);
+            let distance = location.manhattan_distance();
+            let start = location.location();
+
+            location.relative_rotate(rotation, angle);
+            prop_assert_eq!(location.manhattan_distance(), distance);
+
+            for _ in 0..(360 - angle) / 90 {
+                location.relative_rotate(rotation, 90);
+            }
+            prop_assert_eq!(location.location(), start);
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//! An interpreter for navigation instructions such as `N3 F10 R90 L270`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::{Direction, Location, Rotation, Viewpoint};
+use crate::point::Heading;
+
+/// What an instruction does with its value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Move in a compass direction without turning, from `N`, `E`, `S` or `W`.
+    Move(Direction),
+    /// Turn by an angle in degrees, from `L` or `R`.
+    Turn(Rotation),
+    /// Move forward, from `F`.
+    Forward,
+}
+
+/// An action and its value, such as `R90`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub action: Action,
+    pub value: i64,
+}
+
+/// What the instructions steer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Mode {
+    /// Moves and turns steer the ship, which moves forward in the direction it faces.
+    Ship,
+    /// Moves and turns steer a waypoint relative to the ship, which moves forward towards it.
+    Waypoint,
+}
+
+/// The reason an instruction could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseInstructionError {
+    Empty,
+    UnknownAction(char),
+    InvalidValue(String),
+    /// A turn which is not a multiple of 90 degrees.
+    UnalignedAngle(i64),
+}
+
+/// A ship following instructions, which remembers every position it has been at.
+#[derive(Debug, Clone)]
+pub struct Turtle {
+    mode: Mode,
+    location: Location,
+    viewpoint: Viewpoint,
+    waypoint: Location,
+    trail: Vec<Location>,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseInstructionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let action = match chars.next().ok_or(ParseInstructionError::Empty)? {
+            'N' => Action::Move(Direction::North),
+            'E' => Action::Move(Direction::East),
+            'S' => Action::Move(Direction::South),
+            'W' => Action::Move(Direction::West),
+            'L' => Action::Turn(Rotation::Left),
+            'R' => Action::Turn(Rotation::Right),
+            'F' => Action::Forward,
+            c => return Err(ParseInstructionError::UnknownAction(c)),
+        };
+        let value = chars.as_str().parse()
+            .map_err(|_| ParseInstructionError::InvalidValue(chars.as_str().to_string()))?;
+
+        if let Action::Turn(_) = action {
+            if value % 90 != 0 {
+                return Err(ParseInstructionError::UnalignedAngle(value));
+            }
+        }
+        Ok(Instruction { action, value })
+    }
+}
+
+/// Parse the instructions separated by whitespace or newlines.
+pub fn parse(text: &str) -> Result<Vec<Instruction>, ParseInstructionError> {
+    text.split_whitespace().map(str::parse).collect()
+}
+
+impl Turtle {
+    /// Create a ship at (0, 0) which is steered itself, facing the direction.
+    pub fn ship(facing: Direction) -> Self {
+        Turtle::new(Mode::Ship, facing, Location::new())
+    }
+
+    /// Create a ship at (0, 0) which is steered by a waypoint starting after the movements.
+    pub fn waypoint(movements: &[(Direction, i64)]) -> Self {
+        Turtle::new(Mode::Waypoint, Direction::East, Location::new_at_location(movements))
+    }
+
+    fn new(mode: Mode, facing: Direction, waypoint: Location) -> Self {
+        let location = Location::new();
+        Turtle {
+            mode,
+            location,
+            viewpoint: Viewpoint::new(facing),
+            waypoint,
+            trail: vec![location],
+        }
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Return the location of the ship.
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    /// Return the direction the ship faces, which only turns in ship mode.
+    pub fn direction(&self) -> Direction {
+        self.viewpoint.direction()
+    }
+
+    /// Return the waypoint relative to the ship, which only moves in waypoint mode.
+    pub fn waypoint_location(&self) -> &Location {
+        &self.waypoint
+    }
+
+    /// Return every location the ship has been at, starting where it started.
+    ///
+    /// Moves in ship mode add a location per unit step, and a `Forward` in waypoint mode adds one
+    /// per hop to the waypoint. Turns and waypoint moves add nothing.
+    pub fn trail(&self) -> &[Location] {
+        &self.trail
+    }
+
+    pub fn execute(&mut self, instruction: Instruction) {
+        let Instruction { action, value } = instruction;
+        match (self.mode, action) {
+            (Mode::Ship, Action::Move(direction)) => self.walk(direction, value),
+            (Mode::Ship, Action::Turn(rotation)) => self.viewpoint.rotate(rotation, value),
+            (Mode::Ship, Action::Forward) => self.walk(self.viewpoint.direction(), value),
+            (Mode::Waypoint, Action::Move(direction)) => self.waypoint.movement(direction, value),
+            (Mode::Waypoint, Action::Turn(rotation)) => self.waypoint.relative_rotate(rotation, value),
+            (Mode::Waypoint, Action::Forward) => {
+                for _ in 0..value {
+                    self.location.move_to_location(&self.waypoint);
+                    self.trail.push(self.location);
+                }
+            },
+        }
+    }
+
+    pub fn run(&mut self, instructions: &[Instruction]) {
+        instructions.iter().for_each(|&instruction| self.execute(instruction));
+    }
+
+    /// Move the ship one unit at a time, backwards for a negative value.
+    fn walk(&mut self, direction: Direction, value: i64) {
+        let (direction, steps) = if value < 0 {
+            (direction.opposite(), -value)
+        } else {
+            (direction, value)
+        };
+
+        for _ in 0..steps {
+            self.location.movement(direction, 1);
+            self.trail.push(self.location);
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.action {
+            Action::Move(direction) => write!(f, "{}{}", direction, self.value),
+            Action::Turn(rotation) => write!(f, "{}{}", rotation, self.value),
+            Action::Forward => write!(f, "F{}", self.value),
+        }
+    }
+}
+
+impl fmt::Display for ParseInstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseInstructionError::Empty => write!(f, "empty instruction"),
+            ParseInstructionError::UnknownAction(c) => write!(f, "unknown action `{}`", c),
+            ParseInstructionError::InvalidValue(value) => write!(f, "invalid value {:?}", value),
+            ParseInstructionError::UnalignedAngle(angle) => write!(f, "{} is not a multiple of 90 degrees", angle),
+        }
+    }
+}
+
+impl std::error::Error for ParseInstructionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "F10\nN3\nF7\nR90\nF11\n";
+
+    #[test]
+    fn parse_instructions() {
+        let instructions = parse("N3 F10 R90 L270").unwrap();
+        assert_eq!(instructions[0], Instruction { action: Action::Move(Direction::North), value: 3 });
+        assert_eq!(instructions[3], Instruction { action: Action::Turn(Rotation::Left), value: 270 });
+        assert_eq!(instructions.iter().map(ToString::to_string).collect::<Vec<_>>(), vec!["N3", "F10", "R90", "L270"]);
+
+        assert_eq!(parse("F10 X3"), Err(ParseInstructionError::UnknownAction('X')));
+        assert_eq!(parse("Fx"), Err(ParseInstructionError::InvalidValue("x".to_string())));
+        assert_eq!(parse("R45"), Err(ParseInstructionError::UnalignedAngle(45)));
+    }
+
+    #[test]
+    fn ship_mode() {
+        let mut turtle = Turtle::ship(Direction::East);
+        turtle.run(&parse(EXAMPLE).unwrap());
+        assert_eq!(turtle.location().location(), ((Direction::East, 17), (Direction::South, 8)));
+        assert_eq!(turtle.location().manhattan_distance(), 25);
+        assert_eq!(turtle.direction(), Direction::South);
+        // the start, then a location per unit moved by N3 and the three forwards
+        assert_eq!(turtle.trail().len(), 1 + 10 + 3 + 7 + 11);
+        assert_eq!(turtle.trail()[3].location(), ((Direction::East, 3), (Direction::North, 0)));
+        assert_eq!(turtle.trail().last(), Some(turtle.location()));
+    }
+
+    #[test]
+    fn waypoint_mode() {
+        let mut turtle = Turtle::waypoint(&[(Direction::East, 10), (Direction::North, 1)]);
+        turtle.run(&parse(EXAMPLE).unwrap());
+        assert_eq!(turtle.location().location(), ((Direction::East, 214), (Direction::South, 72)));
+        assert_eq!(turtle.location().manhattan_distance(), 286);
+        assert_eq!(turtle.waypoint_location().location(), ((Direction::East, 4), (Direction::South, 10)));
+
+        let trail = turtle.trail();
+        assert_eq!(trail.len(), 1 + 10 + 7 + 11);
+        let forwards: Vec<i64> = [0, 10, 17, 28].iter().map(|&i| trail[i].manhattan_distance()).collect();
+        assert_eq!(forwards, vec![0, 110, 208, 286]);
+    }
+
+    #[test]
+    fn waypoint_forward_records_every_hop() {
+        let mut turtle = Turtle::waypoint(&[(Direction::East, 10), (Direction::North, 1)]);
+        turtle.run(&parse("F10").unwrap());
+
+        let hops = &turtle.trail()[1..];
+        assert_eq!(hops.len(), 10);
+        let east: Vec<i64> = hops.iter().map(|location| location.location().0.1).collect();
+        assert_eq!(east, (1..=10).map(|hop| hop * 10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn full_turns_do_nothing() {
+        let mut turtle = Turtle::waypoint(&[(Direction::East, 10), (Direction::North, 1)]);
+        turtle.run(&parse("L0 R360 L720").unwrap());
+        assert_eq!(turtle.waypoint_location().location(), ((Direction::East, 10), (Direction::North, 1)));
+        assert_eq!(turtle.trail().len(), 1);
+    }
+}
@@ -0,0 +1,254 @@
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, digit1, line_ending, multispace0, none_of, space0, space1};
+use nom::combinator::{map_res, opt, recognize};
+use nom::error::Error;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, pair, preceded, separated_pair, terminated, tuple};
+use nom::{Finish, IResult, Parser};
+
+use std::fmt;
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+/// An error from `parse_all` with the position (starting at 1) where parsing stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+/// Parse an unsigned number, such as `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Parse a number with an optional sign, such as `-42` or `+42`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(
+        recognize(pair(opt(alt((char('-'), char('+')))), digit1)),
+        |s: &str| s.trim_start_matches('+').parse(),
+    )(input)
+}
+
+/// Parse unsigned numbers separated by any amount of spaces or tabs, such as `7  15   30`.
+pub fn unsigned_list<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, unsigned)(input)
+}
+
+/// Parse signed numbers separated by any amount of spaces or tabs, such as `0 -3 6`.
+pub fn signed_list<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, signed)(input)
+}
+
+/// Parse an inclusive range of unsigned numbers, such as `3-7`.
+pub fn range<T: FromStr>(input: &str) -> IResult<&str, RangeInclusive<T>> {
+    separated_pair(unsigned, char('-'), unsigned)
+        .map(|(start, end)| start..=end)
+        .parse(input)
+}
+
+/// Parse the value after a `Label:` header, such as `Time:      7  15   30`.
+pub fn labelled<'a, O, F>(label: &'static str, value: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>> {
+
+    preceded(tuple((tag(label), char(':'), space0)), value)
+}
+
+/// Parse a `Label:` header line followed by the lines of the section body.
+pub fn labelled_section<'a, O, F>(label: &'static str, body: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>> {
+
+    preceded(tuple((tag(label), char(':'), space0, line_ending)), body)
+}
+
+/// Parse a numbered prefix, such as `Card   1:`, returning the number.
+pub fn numbered<'a, T: FromStr>(label: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, T> {
+    delimited(pair(tag(label), space1), unsigned, pair(char(':'), space0))
+}
+
+/// Parse a key and value around a separator, such as `AAA = (BBB, CCC)`.
+pub fn key_value<'a, K, V, FK, FV>(key: FK, separator: &'static str, value: FV) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)>
+where
+    FK: Parser<&'a str, K, Error<&'a str>>,
+    FV: Parser<&'a str, V, Error<&'a str>> {
+
+    separated_pair(key, tuple((space0, tag(separator), space0)), value)
+}
+
+/// Parse a parenthesized pair, such as `(BBB, CCC)`.
+pub fn parenthesized_pair<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, (O, O)>
+where
+    F: Parser<&'a str, O, Error<&'a str>> + Clone {
+
+    delimited(
+        terminated(char('('), space0),
+        separated_pair(item.clone(), pair(char(','), space0), item),
+        preceded(space0, char(')')),
+    )
+}
+
+/// Parse the items of consecutive lines.
+pub fn lines<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>> {
+
+    separated_list1(line_ending, item)
+}
+
+/// Parse sections separated by blank lines.
+pub fn sections<'a, O, F>(section: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>> {
+
+    separated_list1(pair(line_ending, many1(line_ending)), section)
+}
+
+/// Parse consecutive lines of cells into a grid, using the same coordinates as `grid_snake`.
+pub fn grid<'a, T, F>(cell: F) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>>
+where
+    T: fmt::Debug + Eq + Hash + Clone,
+    F: Parser<&'a str, T, Error<&'a str>> {
+
+    let mut rows = lines(many1(cell));
+    move |input| {
+        let (input, rows) = rows(input)?;
+        let mut grid = Grid::new();
+        for (x, row) in rows.into_iter().enumerate() {
+            for (y, entry) in row.into_iter().enumerate() {
+                grid.add_entry((x as i64, y as i64), entry);
+            }
+        }
+        Ok((input, grid))
+    }
+}
+
+/// Parse consecutive lines of characters into a grid.
+pub fn char_grid(input: &str) -> IResult<&str, Grid<char>> {
+    grid(none_of("\r\n"))(input)
+}
+
+/// Run the parser over the whole input, failing if anything other than trailing whitespace remains.
+pub fn parse_all<'a, O, F>(mut parser: F, input: &'a str) -> Result<O, ParseError>
+where
+    F: Parser<&'a str, O, Error<&'a str>> {
+
+    let (remaining, output) = parser.parse(input).finish()
+        .map_err(|e| ParseError::new(input, e.input, format!("{:?}", e.code)))?;
+
+    let (remaining, _) = multispace0::<&str, Error<&str>>(remaining).finish()
+        .map_err(|e| ParseError::new(input, e.input, format!("{:?}", e.code)))?;
+    if !remaining.is_empty() {
+        return Err(ParseError::new(input, remaining, "unconsumed input".to_string()));
+    }
+
+    Ok(output)
+}
+
+impl ParseError {
+    fn new(input: &str, remaining: &str, message: String) -> Self {
+        let consumed = &input[..input.len() - remaining.len()];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed.chars().rev().take_while(|&c| c != '\n').count() + 1;
+        ParseError {
+            line,
+            column,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at line {} column {}", self.message, self.line, self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsigned_list_accepts_spaces_and_tabs() {
+        assert_eq!(unsigned_list::<u64>("7  15\t30\n40"), Ok(("\n40", vec![7, 15, 30])));
+        assert_eq!(unsigned_list::<u64>("1 2 | 3"), Ok((" | 3", vec![1, 2])));
+    }
+
+    #[test]
+    fn signed_list_parses_signs() {
+        assert_eq!(signed_list::<i32>("0 -3  +6"), Ok(("", vec![0, -3, 6])));
+        assert!(unsigned::<u32>("-3").is_err());
+    }
+
+    #[test]
+    fn range_is_inclusive() {
+        assert_eq!(range::<u32>("3-7"), Ok(("", 3..=7)));
+    }
+
+    #[test]
+    fn labelled_skips_header() {
+        let mut parser = labelled("Time", unsigned_list::<u64>);
+        assert_eq!(parser("Time:      7  15   30"), Ok(("", vec![7, 15, 30])));
+    }
+
+    #[test]
+    fn labelled_section_reads_body() {
+        let input = "seed-to-soil map:\n50 98 2\n52 50 48";
+        let mut parser = labelled_section("seed-to-soil map", lines(unsigned_list::<u64>));
+        assert_eq!(parser(input), Ok(("", vec![vec![50, 98, 2], vec![52, 50, 48]])));
+    }
+
+    #[test]
+    fn numbered_returns_number() {
+        assert_eq!(numbered::<u32>("Card")("Card   1: 41 48"), Ok(("41 48", 1)));
+    }
+
+    #[test]
+    fn key_value_with_parenthesized_pair() {
+        let alpha = nom::character::complete::alphanumeric1;
+        let mut parser = key_value(alpha, "=", parenthesized_pair(alpha));
+        assert_eq!(parser("AAA = (BBB, CCC)"), Ok(("", ("AAA", ("BBB", "CCC")))));
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        let input = "1 2\n3\n\n4\n\n\n5 6";
+        let sections = sections(lines(unsigned_list::<u32>))(input);
+        assert_eq!(sections, Ok(("", vec![vec![vec![1, 2], vec![3]], vec![vec![4]], vec![vec![5, 6]]])));
+    }
+
+    #[test]
+    fn char_grid_matches_grid_snake_coordinates() {
+        let (remaining, grid) = char_grid("ab\ncd\n\nef").unwrap();
+        assert_eq!(remaining, "\n\nef");
+        assert_eq!(grid.entry((0, 1)), Some(&'b'));
+        assert_eq!(grid.entry((1, 0)), Some(&'c'));
+        assert_eq!(grid.max_xy(), (1, 1));
+    }
+
+    #[test]
+    fn parse_all_ignores_trailing_whitespace() {
+        assert_eq!(parse_all(unsigned_list::<u32>, "1 2 3\n"), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn parse_all_rejects_unconsumed_input() {
+        let error = parse_all(lines(unsigned_list::<u32>), "1 2\n3 x").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.message, "unconsumed input");
+    }
+
+    #[test]
+    fn parse_all_reports_failure_position() {
+        let error = parse_all(labelled("Time", unsigned_list::<u32>), "Tim: 1").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
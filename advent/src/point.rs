@@ -0,0 +1,272 @@
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::grid::{self, Coord};
+use crate::map;
+
+/// A position on a grid as a row, which grows downwards, and a column, which grows to the right.
+///
+/// This is the same layout as a `Coord` from `grid_snake`, where x is the line index.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point(pub Coord);
+
+/// A direction which can be turned and followed, shared by `grid::Direction` and
+/// `map::Direction`.
+pub trait Heading: Copy {
+    /// Return the direction after a quarter turn counter-clockwise.
+    fn turn_left(self) -> Self;
+
+    /// Return the direction after a quarter turn clockwise.
+    fn turn_right(self) -> Self;
+
+    fn opposite(self) -> Self;
+
+    /// Return the point one step in the direction from the origin.
+    fn unit(self) -> Point;
+
+    /// Return the point `distance` steps in the direction from `point`.
+    fn step(self, point: Point, distance: i64) -> Point {
+        point + self.unit() * distance
+    }
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point((0, 0));
+
+    pub fn new(row: i64, col: i64) -> Self {
+        Point((row, col))
+    }
+
+    pub fn row(self) -> i64 {
+        self.0.0
+    }
+
+    pub fn col(self) -> i64 {
+        self.0.1
+    }
+
+    /// Return the manhattan distance between the points.
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.row() - other.row()).abs() + (self.col() - other.col()).abs()
+    }
+}
+
+impl From<Coord> for Point {
+    fn from(coord: Coord) -> Self {
+        Point(coord)
+    }
+}
+
+impl From<Point> for Coord {
+    fn from(point: Point) -> Self {
+        point.0
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row() + other.row(), self.col() + other.col())
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row() - other.row(), self.col() - other.col())
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, value: i64) -> Point {
+        Point::new(self.row() * value, self.col() * value)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        self * -1
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Heading for grid::Direction {
+    fn turn_left(self) -> Self {
+        use grid::Direction::*;
+        match self {
+            Up => Left,
+            Left => Down,
+            Down => Right,
+            Right => Up,
+            UpLeft => DownLeft,
+            DownLeft => DownRight,
+            DownRight => UpRight,
+            UpRight => UpLeft,
+        }
+    }
+
+    fn turn_right(self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    fn opposite(self) -> Self {
+        self.get_opposite()
+    }
+
+    fn unit(self) -> Point {
+        use grid::Direction::*;
+        match self {
+            Up => Point::new(-1, 0),
+            Down => Point::new(1, 0),
+            Left => Point::new(0, -1),
+            Right => Point::new(0, 1),
+            UpLeft => Point::new(-1, -1),
+            UpRight => Point::new(-1, 1),
+            DownLeft => Point::new(1, -1),
+            DownRight => Point::new(1, 1),
+        }
+    }
+}
+
+impl Heading for map::Direction {
+    fn turn_left(self) -> Self {
+        grid::Direction::from(self).turn_left().try_into().expect("Cardinal directions turn into cardinal directions")
+    }
+
+    fn turn_right(self) -> Self {
+        grid::Direction::from(self).turn_right().try_into().expect("Cardinal directions turn into cardinal directions")
+    }
+
+    fn opposite(self) -> Self {
+        grid::Direction::from(self).opposite().try_into().expect("Cardinal directions turn into cardinal directions")
+    }
+
+    fn unit(self) -> Point {
+        grid::Direction::from(self).unit()
+    }
+}
+
+/// North is up on a grid, so the compass directions are the cardinal grid directions.
+impl From<map::Direction> for grid::Direction {
+    fn from(direction: map::Direction) -> Self {
+        match direction {
+            map::Direction::North => grid::Direction::Up,
+            map::Direction::East => grid::Direction::Right,
+            map::Direction::South => grid::Direction::Down,
+            map::Direction::West => grid::Direction::Left,
+        }
+    }
+}
+
+impl TryFrom<grid::Direction> for map::Direction {
+    type Error = grid::Direction;
+
+    /// Convert a cardinal grid direction, returning a diagonal one as the error.
+    fn try_from(direction: grid::Direction) -> Result<Self, grid::Direction> {
+        match direction {
+            grid::Direction::Up => Ok(map::Direction::North),
+            grid::Direction::Right => Ok(map::Direction::East),
+            grid::Direction::Down => Ok(map::Direction::South),
+            grid::Direction::Left => Ok(map::Direction::West),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row(), self.col())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn grid_direction() -> impl Strategy<Value = grid::Direction> {
+        use grid::Direction::*;
+        proptest::sample::select(vec![Left, Right, Up, Down, UpLeft, UpRight, DownLeft, DownRight])
+    }
+
+    fn map_direction() -> impl Strategy<Value = map::Direction> {
+        use map::Direction::*;
+        proptest::sample::select(vec![North, East, South, West])
+    }
+
+    fn point() -> impl Strategy<Value = Point> {
+        (-1000i64..1000, -1000i64..1000).prop_map(Point)
+    }
+
+    fn check_heading<H: Heading + PartialEq + fmt::Debug>(heading: H) -> Result<(), TestCaseError> {
+        prop_assert_eq!(heading.turn_left().turn_right(), heading);
+        prop_assert_eq!(heading.turn_left().turn_left(), heading.opposite());
+        prop_assert_eq!(heading.turn_right().turn_right().turn_right().turn_right(), heading);
+        prop_assert_eq!(heading.opposite().unit(), -heading.unit());
+
+        let (unit, left) = (heading.unit(), heading.turn_left().unit());
+        prop_assert_eq!((left.row(), left.col()), (-unit.col(), unit.row()));
+        Ok(())
+    }
+
+    #[test]
+    fn point_ops() {
+        let point = Point::new(2, -3);
+        assert_eq!(point + Point::new(1, 1), Point::new(3, -2));
+        assert_eq!(point - Point::new(1, 1), Point::new(1, -4));
+        assert_eq!(point * 3, Point::new(6, -9));
+        assert_eq!(point.manhattan(Point::ORIGIN), 5);
+        assert_eq!(Coord::from(point), (2, -3));
+        assert_eq!(point.to_string(), "(2, -3)");
+    }
+
+    #[test]
+    fn compass_is_grid() {
+        assert_eq!(grid::Direction::from(map::Direction::North), grid::Direction::Up);
+        assert_eq!(map::Direction::try_from(grid::Direction::Left), Ok(map::Direction::West));
+        assert_eq!(map::Direction::try_from(grid::Direction::UpLeft), Err(grid::Direction::UpLeft));
+        assert_eq!(map::Direction::North.turn_right(), map::Direction::East);
+        assert_eq!(map::Direction::South.unit(), Point::new(1, 0));
+    }
+
+    proptest! {
+        #[test]
+        fn grid_directions_turn(direction in grid_direction()) {
+            check_heading(direction)?;
+        }
+
+        #[test]
+        fn map_directions_turn(direction in map_direction()) {
+            check_heading(direction)?;
+            let grid_direction = grid::Direction::from(direction);
+            prop_assert_eq!(map::Direction::try_from(grid_direction), Ok(direction));
+            prop_assert_eq!(grid_direction.unit(), direction.unit());
+            prop_assert_eq!(grid::Direction::from(direction.turn_left()), grid_direction.turn_left());
+        }
+
+        #[test]
+        fn steps_match_next_coord(direction in grid_direction(), point in point(), distance in -100i64..100) {
+            let stepped = direction.step(point, distance);
+            prop_assert_eq!(stepped.0, direction.next_coord_by_value(point.0, distance));
+            prop_assert_eq!(stepped - point, direction.unit() * distance);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use scraper::{ElementRef, Html, Selector};
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::fetch::{Client, FetchError};
+
+/// The description of a day, with the examples copied out of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The description of every unlocked part as Markdown.
+    pub markdown: String,
+    /// The text of every `<pre><code>` block, in page order.
+    pub examples: Vec<String>,
+    /// The guessed answer to the example of each unlocked part, or `None` where there was no guess.
+    pub expected: Vec<Option<String>>,
+}
+
+impl Puzzle {
+    /// Read the puzzle from the HTML of its page, which has an `<article>` per unlocked part.
+    pub fn from_html(html: &str) -> Self {
+        let document = Html::parse_document(html);
+        let article = Selector::parse("article").expect("Valid selector");
+        let pre_code = Selector::parse("pre > code").expect("Valid selector");
+
+        let articles: Vec<ElementRef> = document.select(&article).collect();
+        let markdown = articles.iter()
+            .map(|a| html2md::parse_html(&a.inner_html()).trim().to_string())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let examples = articles.iter()
+            .flat_map(|a| a.select(&pre_code))
+            .map(|code| code.text().collect())
+            .collect();
+        let expected = articles.iter()
+            .map(|a| expected_answer(*a))
+            .collect();
+
+        Puzzle {
+            markdown,
+            examples,
+            expected,
+        }
+    }
+
+    /// Write `puzzle.md`, an `example_N` file per example starting at 1, and `expected_answers`
+    /// with a line per part which has a guessed answer into the directory.
+    pub fn write<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join("puzzle.md"), format!("{}\n", self.markdown))?;
+        for (i, example) in self.examples.iter().enumerate() {
+            fs::write(dir.join(format!("example_{}", i + 1)), example)?;
+        }
+
+        let expected: String = self.expected.iter()
+            .enumerate()
+            .filter_map(|(i, answer)| answer.as_ref().map(|answer| format!("part {}: {}\n", i + 1, answer)))
+            .collect();
+        fs::write(dir.join("expected_answers"), expected)
+    }
+}
+
+/// Guess the answer to the example of a part, which is the last emphasized code in its article.
+fn expected_answer(article: ElementRef) -> Option<String> {
+    let emphasized_code = Selector::parse("code > em, em > code").expect("Valid selector");
+    let emphasized = Selector::parse("em").expect("Valid selector");
+    article.select(&emphasized_code)
+        .last()
+        .or_else(|| article.select(&emphasized).last())
+        .map(|e| e.text().collect::<String>().trim().to_string())
+}
+
+impl Client {
+    /// Return the puzzle of the day with every part unlocked so far.
+    ///
+    /// The page changes once a part is solved, so it is never cached.
+    pub fn puzzle(&mut self, year: u32, day: u32) -> Result<Puzzle, FetchError> {
+        let html = self.get(&format!("/{}/day/{}", year, day))
+            .map_err(|e| e.for_day(year, day))?;
+        Ok(Puzzle::from_html(&html))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::stub::stub_server;
+    use std::time::Duration;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>In this example, the calibration values are <code>12</code> and <code>38</code>. Adding these together produces <code><em>50</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54331</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+</code></pre>
+<p>Adding these together produces <em>29</em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn from_html_extracts_examples_and_answers() {
+        let puzzle = Puzzle::from_html(PAGE);
+        assert_eq!(puzzle.examples, vec!["1abc2\npqr3stu8vwx\n", "two1nine\n"]);
+        assert_eq!(puzzle.expected, vec![Some("50".to_string()), Some("29".to_string())]);
+        assert!(puzzle.markdown.contains("Day 1: Trebuchet?!"));
+        assert!(puzzle.markdown.contains("--- Part Two ---"));
+        assert!(!puzzle.markdown.contains("<article"));
+        assert!(!puzzle.markdown.contains("54331"));
+    }
+
+    #[test]
+    fn write_creates_files() {
+        let dir = tempfile::tempdir().unwrap();
+        Puzzle::from_html(PAGE).write(dir.path()).unwrap();
+
+        assert_eq!(fs::read_to_string(dir.path().join("example_1")).unwrap(), "1abc2\npqr3stu8vwx\n");
+        assert_eq!(fs::read_to_string(dir.path().join("example_2")).unwrap(), "two1nine\n");
+        assert_eq!(fs::read_to_string(dir.path().join("expected_answers")).unwrap(), "part 1: 50\npart 2: 29\n");
+        assert!(dir.path().join("puzzle.md").exists());
+    }
+
+    #[test]
+    fn parts_without_an_answer_keep_their_numbers() {
+        let page = r#"<main>
+<article><h2>--- Day 2 ---</h2><p>No emphasized answer here.</p></article>
+<article><h2>--- Part Two ---</h2><p>This produces <em>2286</em>.</p></article>
+</main>"#;
+        let puzzle = Puzzle::from_html(page);
+        assert_eq!(puzzle.expected, vec![None, Some("2286".to_string())]);
+
+        let dir = tempfile::tempdir().unwrap();
+        puzzle.write(dir.path()).unwrap();
+        assert_eq!(fs::read_to_string(dir.path().join("expected_answers")).unwrap(), "part 2: 2286\n");
+    }
+
+    #[test]
+    fn puzzle_is_downloaded_from_base_url() {
+        let (url, received) = stub_server(200, PAGE);
+        let cache = tempfile::tempdir().unwrap();
+        let mut client = Client::new("abc123")
+            .with_base_url(&url)
+            .with_cache_dir(cache.path())
+            .with_min_interval(Duration::ZERO);
+
+        assert_eq!(client.puzzle(2023, 1).unwrap().expected, vec![Some("50".to_string()), Some("29".to_string())]);
+        assert_eq!(received.lock().unwrap()[0].url, "/2023/day/1");
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+use crate::day::{Day, Solution};
+
+/// Every solved day of every year, looked up by `(year, day)`.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u32, u32), Solution>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Add the solution of a day, replacing any earlier one for the same day.
+    pub fn register<D: Day>(&mut self, year: u32, day: u32) -> &mut Self {
+        self.solutions.insert((year, day), Solution::new::<D>(year, day));
+        self
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Option<&Solution> {
+        self.solutions.get(&(year, day))
+    }
+
+    /// Return every year with a solved day, in order.
+    pub fn years(&self) -> Vec<u32> {
+        let mut years: Vec<u32> = self.solutions.keys().map(|&(year, _)| year).collect();
+        years.dedup();
+        years
+    }
+
+    /// Return the solutions in order, limited to a year and to some days when they are given.
+    pub fn select<'a>(&'a self, year: Option<u32>, days: &'a [u32]) -> impl Iterator<Item = &'a Solution> + 'a {
+        self.solutions.values()
+            .filter(move |s| year.is_none_or(|year| s.year() == year))
+            .filter(move |s| days.is_empty() || days.contains(&s.day()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Display;
+
+    struct Lines;
+
+    impl Day for Lines {
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            input.lines().collect()
+        }
+
+        fn part_one(lines: &Self::Input<'_>) -> impl Display {
+            lines.len()
+        }
+
+        fn part_two(lines: &Self::Input<'_>) -> impl Display {
+            lines.iter().map(|l| l.len()).sum::<usize>()
+        }
+    }
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry
+            .register::<Lines>(2023, 2)
+            .register::<Lines>(2022, 25)
+            .register::<Lines>(2023, 1);
+        registry
+    }
+
+    #[test]
+    fn select_orders_by_year_and_day() {
+        let registry = registry();
+        let labels: Vec<String> = registry.select(None, &[]).map(|s| s.label()).collect();
+        assert_eq!(labels, vec!["2022/day25", "2023/day1", "2023/day2"]);
+        assert_eq!(registry.years(), vec![2022, 2023]);
+    }
+
+    #[test]
+    fn select_filters_year_and_days() {
+        let registry = registry();
+        assert_eq!(registry.select(Some(2023), &[]).count(), 2);
+        assert_eq!(registry.select(Some(2023), &[2]).map(|s| s.day()).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(registry.select(Some(2021), &[]).count(), 0);
+    }
+
+    #[test]
+    fn registered_day_runs() {
+        let report = registry().get(2022, 25).unwrap().bench("ab\ncde\n", 1);
+        assert_eq!(report.label, "2022/day25");
+        assert_eq!((report.part_one.as_str(), report.part_two.as_str()), ("2", "5"));
+    }
+}
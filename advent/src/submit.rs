@@ -0,0 +1,282 @@
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::fetch::{Client, FetchError};
+
+/// The site's response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was submitted too soon after the last one and was not checked.
+    Wait { seconds: u64 },
+    /// The part has already been solved.
+    AlreadySolved,
+}
+
+/// A submitted answer and the site's verdict.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+/// Every answer submitted so far, stored as JSON.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuessHistory {
+    guesses: Vec<Guess>,
+}
+
+/// Why an answer is not worth submitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part has already been answered correctly.
+    AlreadySolved { answer: String },
+    /// The same answer was already wrong.
+    AlreadyWrong,
+    /// The answer is at least a guess which was too high.
+    TooHigh { bound: i64 },
+    /// The answer is at most a guess which was too low.
+    TooLow { bound: i64 },
+}
+
+impl Verdict {
+    /// Read the verdict from the article of the page returned after submitting.
+    pub fn from_response(body: &str) -> Option<Self> {
+        if body.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if body.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if body.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if body.contains("You gave an answer too recently") {
+            Some(Verdict::Wait { seconds: wait_seconds(body).unwrap_or(60) })
+        } else if body.contains("Did you already complete it") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+/// Return the time left in a `You have 4m 32s left to wait` message.
+fn wait_seconds(body: &str) -> Option<u64> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+    body[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.len() - 1);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+impl Client {
+    /// Submit the answer to a part of the day and return the site's verdict.
+    pub fn submit(&mut self, year: u32, day: u32, part: u32, answer: &str) -> Result<Verdict, FetchError> {
+        let level = part.to_string();
+        let body = self.post_form(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &level), ("answer", answer)])?;
+
+        Verdict::from_response(&body).ok_or(FetchError::Status { code: 200, body })
+    }
+}
+
+impl GuessHistory {
+    /// Load the history from a JSON file, which is empty if the file does not exist yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(GuessHistory::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Save the history as a JSON file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, json + "\n")
+    }
+
+    /// Return the guesses for a part of the day, oldest first.
+    pub fn guesses(&self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Guess> {
+        self.guesses.iter()
+            .filter(move |g| g.year == year && g.day == day && g.part == part)
+    }
+
+    /// Add a guess with its verdict, stamped with the current time.
+    pub fn record(&mut self, year: u32, day: u32, part: u32, answer: &str, verdict: Verdict) {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_secs();
+        self.guesses.push(Guess {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at,
+        });
+    }
+
+    /// Check the answer against the earlier guesses, refusing it if the verdict is already known.
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<(), Refusal> {
+        let mut too_high: Option<i64> = None;
+        let mut too_low: Option<i64> = None;
+        for guess in self.guesses(year, day, part) {
+            match guess.verdict {
+                Verdict::Correct => return Err(Refusal::AlreadySolved { answer: guess.answer.clone() }),
+                Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong if guess.answer == answer => {
+                    return Err(Refusal::AlreadyWrong);
+                },
+                Verdict::TooHigh => too_high = min_bound(too_high, guess.answer.parse().ok()),
+                Verdict::TooLow => too_low = max_bound(too_low, guess.answer.parse().ok()),
+                _ => {},
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            match (too_high, too_low) {
+                (Some(bound), _) if value >= bound => return Err(Refusal::TooHigh { bound }),
+                (_, Some(bound)) if value <= bound => return Err(Refusal::TooLow { bound }),
+                _ => {},
+            }
+        }
+        Ok(())
+    }
+}
+
+fn min_bound(bound: Option<i64>, value: Option<i64>) -> Option<i64> {
+    match (bound, value) {
+        (Some(b), Some(v)) => Some(b.min(v)),
+        (b, v) => b.or(v),
+    }
+}
+
+fn max_bound(bound: Option<i64>, value: Option<i64>) -> Option<i64> {
+    match (bound, value) {
+        (Some(b), Some(v)) => Some(b.max(v)),
+        (b, v) => b.or(v),
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::TooHigh => write!(f, "That's not the right answer; it is too high."),
+            Verdict::TooLow => write!(f, "That's not the right answer; it is too low."),
+            Verdict::Wrong => write!(f, "That's not the right answer."),
+            Verdict::Wait { seconds } => write!(f, "Answered too recently; wait {}s before trying again.", seconds),
+            Verdict::AlreadySolved => write!(f, "This part is already solved."),
+        }
+    }
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => write!(f, "already solved with {}", answer),
+            Refusal::AlreadyWrong => write!(f, "this answer was already wrong"),
+            Refusal::TooHigh { bound } => write!(f, "{} was already too high", bound),
+            Refusal::TooLow { bound } => write!(f, "{} was already too low", bound),
+        }
+    }
+}
+
+impl std::error::Error for Refusal {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::stub::stub_server;
+
+    #[test]
+    fn verdict_from_response() {
+        assert_eq!(Verdict::from_response("<p>That's the right answer!  You are one gold star closer.</p>"), Some(Verdict::Correct));
+        assert_eq!(Verdict::from_response("<p>That's not the right answer; your answer is too high.</p>"), Some(Verdict::TooHigh));
+        assert_eq!(Verdict::from_response("<p>That's not the right answer; your answer is too low.</p>"), Some(Verdict::TooLow));
+        assert_eq!(Verdict::from_response("<p>That's not the right answer.  If you're stuck...</p>"), Some(Verdict::Wrong));
+        assert_eq!(Verdict::from_response("<p>You don't seem to be solving the right level.  Did you already complete it?</p>"), Some(Verdict::AlreadySolved));
+        assert_eq!(Verdict::from_response("<html></html>"), None);
+    }
+
+    #[test]
+    fn verdict_wait_reads_time_left() {
+        let body = "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait.</p>";
+        assert_eq!(Verdict::from_response(body), Some(Verdict::Wait { seconds: 272 }));
+        assert_eq!(wait_seconds("You have 45s left to wait."), Some(45));
+    }
+
+    #[test]
+    fn check_refuses_known_answers() {
+        let mut history = GuessHistory::default();
+        history.record(2023, 1, 1, "abc", Verdict::Wrong);
+        history.record(2023, 1, 1, "100", Verdict::TooHigh);
+        history.record(2023, 1, 1, "150", Verdict::TooHigh);
+        history.record(2023, 1, 1, "20", Verdict::TooLow);
+        history.record(2023, 1, 1, "60", Verdict::Wait { seconds: 30 });
+
+        assert_eq!(history.check(2023, 1, 1, "abc"), Err(Refusal::AlreadyWrong));
+        assert_eq!(history.check(2023, 1, 1, "120"), Err(Refusal::TooHigh { bound: 100 }));
+        assert_eq!(history.check(2023, 1, 1, "20"), Err(Refusal::AlreadyWrong));
+        assert_eq!(history.check(2023, 1, 1, "5"), Err(Refusal::TooLow { bound: 20 }));
+        assert_eq!(history.check(2023, 1, 1, "60"), Ok(()));
+        assert_eq!(history.check(2023, 1, 2, "120"), Ok(()));
+
+        history.record(2023, 1, 1, "60", Verdict::Correct);
+        assert_eq!(history.check(2023, 1, 1, "61"), Err(Refusal::AlreadySolved { answer: "60".to_string() }));
+    }
+
+    #[test]
+    fn history_round_trips_through_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("guesses.json");
+        assert_eq!(GuessHistory::load(&path).unwrap(), GuessHistory::default());
+
+        let mut history = GuessHistory::default();
+        history.record(2023, 7, 2, "5905", Verdict::Correct);
+        history.save(&path).unwrap();
+        assert_eq!(GuessHistory::load(&path).unwrap(), history);
+    }
+
+    #[test]
+    fn submit_posts_level_and_answer() {
+        let (url, received) = stub_server(200, "<article><p>That's not the right answer; your answer is too low.</p></article>");
+        let cache = tempfile::tempdir().unwrap();
+        let mut client = Client::new("abc123")
+            .with_base_url(&url)
+            .with_cache_dir(cache.path())
+            .with_min_interval(Duration::ZERO);
+
+        assert_eq!(client.submit(2023, 6, 2, "42").unwrap(), Verdict::TooLow);
+
+        let received = received.lock().unwrap();
+        assert_eq!(received[0].url, "/2023/day/6/answer");
+        assert_eq!(received[0].body, "level=2&answer=42");
+        assert_eq!(received[0].cookie.as_deref(), Some("session=abc123"));
+    }
+}
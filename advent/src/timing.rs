@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::day::Day;
+
+/// How long each step of a day took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+/// The answers and timings of a day run one or more times.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub label: String,
+    pub part_one: String,
+    pub part_two: String,
+    pub runs: usize,
+    /// The median of each step on its own.
+    pub median: Timing,
+    /// The fastest run of each step on its own.
+    pub min: Timing,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+/// Run the day once on the input, returning both answers and how long each step took.
+pub fn time<D: Day>(input: &str) -> (String, String, Timing) {
+    let start = Instant::now();
+    let parsed = D::parse(input);
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let part_one = D::part_one(&parsed);
+    let part_one_time = start.elapsed();
+
+    let start = Instant::now();
+    let part_two = D::part_two(&parsed);
+    let part_two_time = start.elapsed();
+
+    let timing = Timing {
+        parse,
+        part_one: part_one_time,
+        part_two: part_two_time,
+    };
+    (part_one.to_string(), part_two.to_string(), timing)
+}
+
+/// Run the day `runs` times on the input, reporting the median and fastest time of each step.
+pub fn bench<D: Day>(label: &str, input: &str, runs: usize) -> Report {
+    let runs = runs.max(1);
+    let (part_one, part_two, first) = time::<D>(input);
+    let mut timings = vec![first];
+    timings.extend((1..runs).map(|_| time::<D>(input).2));
+
+    let step = |f: fn(&Timing) -> Duration| {
+        let mut durations: Vec<Duration> = timings.iter().map(f).collect();
+        durations.sort_unstable();
+        (durations[durations.len() / 2], durations[0])
+    };
+    let (parse_median, parse_min) = step(|t| t.parse);
+    let (part_one_median, part_one_min) = step(|t| t.part_one);
+    let (part_two_median, part_two_min) = step(|t| t.part_two);
+
+    Report {
+        label: label.to_string(),
+        part_one,
+        part_two,
+        runs,
+        median: Timing { parse: parse_median, part_one: part_one_median, part_two: part_two_median },
+        min: Timing { parse: parse_min, part_one: part_one_min, part_two: part_two_min },
+    }
+}
+
+/// Format the reports as a table of answers and median times, with the fastest total when
+/// there was more than one run.
+pub fn table(reports: &[Report]) -> String {
+    let mut table = format!("{:<10} {:>16} {:>16} {:>10} {:>10} {:>10} {:>10}",
+        "day", "part 1", "part 2", "parse", "part 1", "part 2", "total");
+    let repeated = reports.iter().any(|r| r.runs > 1);
+    if repeated {
+        table.push_str(&format!(" {:>10}", "min total"));
+    }
+    table.push('\n');
+
+    for report in reports {
+        let _ = write!(table, "{:<10} {:>16} {:>16} {:>10.1?} {:>10.1?} {:>10.1?} {:>10.1?}",
+            report.label, report.part_one, report.part_two,
+            report.median.parse, report.median.part_one, report.median.part_two, report.median.total());
+        if repeated {
+            let _ = write!(table, " {:>10.1?}", report.min.total());
+        }
+        table.push('\n');
+    }
+
+    let total: Duration = reports.iter().map(|r| r.median.total()).sum();
+    let _ = writeln!(table, "{:<10} {:>77.1?}", "total", total);
+    table
+}
+
+/// Format the change in median total time of every day in both sets of reports, marking days
+/// which became more than `threshold` slower (such as `0.1` for 10%) or changed their answers.
+pub fn compare(old: &[Report], new: &[Report], threshold: f64) -> String {
+    let mut table = format!("{:<10} {:>10} {:>10} {:>8}\n", "day", "old", "new", "change");
+    for report in new {
+        let Some(previous) = old.iter().find(|r| r.label == report.label) else {
+            continue;
+        };
+
+        let old_total = previous.median.total().as_secs_f64();
+        let new_total = report.median.total().as_secs_f64();
+        let change = if old_total > 0.0 { new_total / old_total - 1.0 } else { 0.0 };
+        let _ = write!(table, "{:<10} {:>10.1?} {:>10.1?} {:>+7.1}%",
+            report.label, previous.median.total(), report.median.total(), change * 100.0);
+        if change > threshold {
+            table.push_str("  slower");
+        }
+        if (&previous.part_one, &previous.part_two) != (&report.part_one, &report.part_two) {
+            table.push_str("  answers changed");
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// Save the reports as JSON, so a later run can be compared against them.
+pub fn save<P: AsRef<Path>>(path: P, reports: &[Report]) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(reports).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::write(path, json + "\n")
+}
+
+/// Load reports saved by `save`.
+pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Vec<Report>> {
+    let json = fs::read_to_string(path)?;
+    serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Day for Sum {
+        type Input<'a> = Vec<u32>;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            input.lines().map(|l| l.parse().unwrap()).collect()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> impl std::fmt::Display {
+            input.iter().sum::<u32>()
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> impl std::fmt::Display {
+            input.iter().max().copied().unwrap_or(0)
+        }
+    }
+
+    fn report(label: &str, total_millis: u64, part_one: &str) -> Report {
+        let timing = Timing { parse: Duration::ZERO, part_one: Duration::from_millis(total_millis), part_two: Duration::ZERO };
+        Report {
+            label: label.to_string(),
+            part_one: part_one.to_string(),
+            part_two: "0".to_string(),
+            runs: 1,
+            median: timing,
+            min: timing,
+        }
+    }
+
+    #[test]
+    fn bench_reports_answers_and_runs() {
+        let report = bench::<Sum>("sum", "1\n2\n3\n", 5);
+        assert_eq!((report.part_one.as_str(), report.part_two.as_str()), ("6", "3"));
+        assert_eq!(report.runs, 5);
+        assert!(report.min.total() <= report.median.total());
+    }
+
+    #[test]
+    fn table_has_a_row_per_report() {
+        let table = table(&[report("day1", 3, "142"), report("day2", 5, "8")]);
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(rows.len(), 4);
+        assert!(rows[1].starts_with("day1") && rows[1].contains("142"));
+        assert!(rows[3].starts_with("total") && rows[3].ends_with("8.0ms"));
+    }
+
+    #[test]
+    fn compare_marks_regressions() {
+        let old = [report("day1", 10, "142"), report("day2", 10, "8")];
+        let new = [report("day1", 20, "142"), report("day2", 10, "9"), report("day3", 1, "1")];
+        let comparison = compare(&old, &new, 0.1);
+        let rows: Vec<&str> = comparison.lines().collect();
+        assert_eq!(rows.len(), 3);
+        assert!(rows[1].contains("+100.0%") && rows[1].ends_with("slower"));
+        assert!(rows[2].ends_with("answers changed"));
+    }
+
+    #[test]
+    fn reports_round_trip_through_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("timings.json");
+        let reports = vec![report("day1", 3, "142")];
+        save(&path, &reports).unwrap();
+        assert_eq!(load(&path).unwrap(), reports);
+    }
+}
@@ -0,0 +1,5 @@
+#[test]
+fn from_grid_char_derive_errors() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
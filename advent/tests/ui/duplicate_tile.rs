@@ -0,0 +1,11 @@
+use advent::FromGridChar;
+
+#[derive(FromGridChar)]
+enum Tile {
+    #[tile('#')]
+    Wall,
+    #[tile('#')]
+    Rock,
+}
+
+fn main() {}
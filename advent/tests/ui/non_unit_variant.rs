@@ -0,0 +1,11 @@
+use advent::FromGridChar;
+
+#[derive(FromGridChar)]
+enum Tile {
+    #[tile('.')]
+    Open,
+    #[tile('#')]
+    Wall(u8),
+}
+
+fn main() {}
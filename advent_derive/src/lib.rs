@@ -0,0 +1,202 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use regex::Regex;
+use syn::parse::ParseStream;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitChar, LitStr, PathArguments, Token, Type};
+
+/// How a struct field is populated from the regex captures.
+enum FieldKind {
+    /// A required group from the first match.
+    Required,
+    /// An optional group from the first match.
+    Optional,
+    /// A group collected from every match on the line.
+    Repeated,
+}
+
+/// Derive `advent::FromRegex` for a struct with named fields.
+///
+/// The `#[regex("...")]` attribute holds the pattern, and every field is filled from the named
+/// capture group of the same name by its `FromStr` implementation. `Option` fields are `None`
+/// when their group does not participate, and `Vec` fields collect their group from every match.
+/// The regex is compiled once, on first use, and kept in a static.
+#[proc_macro_derive(FromRegex, attributes(regex))]
+pub fn derive_from_regex(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let regex_str = regex_attribute(input)?;
+    let regex = Regex::new(&regex_str.value())
+        .map_err(|e| syn::Error::new(regex_str.span(), format!("Invalid regex: {}", e)))?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new(Span::call_site(), "FromRegex requires a struct with named fields")),
+        },
+        _ => return Err(syn::Error::new(Span::call_site(), "FromRegex can only be derived for structs")),
+    };
+
+    let field_values = fields.iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("Named field");
+            let group = ident.to_string();
+            if !regex.capture_names().flatten().any(|n| n == group) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("Regex has no capture group named `{}`", group)));
+            }
+
+            let value = match field_kind(&field.ty) {
+                FieldKind::Required => quote! { ::advent::__private::required(&captures, #group)? },
+                FieldKind::Optional => quote! { ::advent::__private::optional(&captures, #group)? },
+                FieldKind::Repeated => quote! { ::advent::__private::repeated(&captures, #group)? },
+            };
+            Ok(quote! { #ident: #value })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::advent::FromRegex for #name #ty_generics #where_clause {
+            fn regex() -> &'static str {
+                #regex_str
+            }
+
+            fn compiled_regex() -> &'static ::advent::__private::Regex {
+                static REGEX: ::std::sync::OnceLock<::advent::__private::Regex> = ::std::sync::OnceLock::new();
+                REGEX.get_or_init(|| ::advent::__private::Regex::new(#regex_str).expect("Valid regex"))
+            }
+
+            fn from(capture_matches: ::advent::__private::CaptureMatches<'_, '_>) -> ::std::result::Result<Self, ::advent::FromRegexError> {
+                let captures: ::std::vec::Vec<_> = capture_matches.collect();
+                if captures.is_empty() {
+                    return ::std::result::Result::Err(::advent::FromRegexError::NoMatch);
+                }
+
+                ::std::result::Result::Ok(#name {
+                    #(#field_values,)*
+                })
+            }
+        }
+    })
+}
+
+/// Return the pattern from the `#[regex("...")]` attribute.
+fn regex_attribute(input: &DeriveInput) -> syn::Result<LitStr> {
+    input.attrs.iter()
+        .find(|attr| attr.path().is_ident("regex"))
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "FromRegex requires a #[regex(\"...\")] attribute"))?
+        .parse_args::<LitStr>()
+}
+
+/// Return how the field should be populated, based on its outermost type.
+fn field_kind(ty: &Type) -> FieldKind {
+    let segment = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last(),
+        _ => None,
+    };
+    let is_generic = segment
+        .map(|s| matches!(&s.arguments, PathArguments::AngleBracketed(args) if args.args.len() == 1))
+        .unwrap_or(false);
+
+    match segment {
+        Some(segment) if is_generic && segment.ident == "Option" => FieldKind::Optional,
+        Some(segment) if is_generic && segment.ident == "Vec" => FieldKind::Repeated,
+        _ => FieldKind::Required,
+    }
+}
+
+/// Derive `advent::FromGridChar` for an enum of unit variants.
+///
+/// Every variant has a `#[tile('#')]` attribute with its character, and a variant which marks a
+/// position such as the start has its name as well, with `#[tile('S', marker = "start")]`.
+#[proc_macro_derive(FromGridChar, attributes(tile))]
+pub fn derive_from_grid_char(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_grid_char(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_grid_char(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => return Err(syn::Error::new(Span::call_site(), "FromGridChar can only be derived for enums")),
+    };
+
+    let mut tiles: Vec<(&Ident, LitChar, Option<LitStr>)> = Vec::new();
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(variant.ident.span(), "FromGridChar requires unit variants"));
+        }
+        let (c, marker) = tile_attribute(variant)?;
+        if let Some((other, _, _)) = tiles.iter().find(|(_, t, _)| t.value() == c.value()) {
+            return Err(syn::Error::new(
+                c.span(),
+                format!("Tile {:?} is already used by `{}`", c.value(), other)));
+        }
+        tiles.push((&variant.ident, c, marker));
+    }
+
+    let from_arms = tiles.iter().map(|(ident, c, _)| quote! { #c => ::std::option::Option::Some(#name::#ident) });
+    let to_arms = tiles.iter().map(|(ident, c, _)| quote! { #name::#ident => #c });
+    let marker_arms = tiles.iter()
+        .filter_map(|(ident, _, marker)| marker.as_ref().map(|m| quote! { #name::#ident => ::std::option::Option::Some(#m) }));
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::advent::FromGridChar for #name #ty_generics #where_clause {
+            fn from_grid_char(c: char) -> ::std::option::Option<Self> {
+                match c {
+                    #(#from_arms,)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+
+            fn to_grid_char(&self) -> char {
+                match self {
+                    #(#to_arms,)*
+                }
+            }
+
+            #[allow(unreachable_patterns)]
+            fn marker(&self) -> ::std::option::Option<&'static str> {
+                match self {
+                    #(#marker_arms,)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    })
+}
+
+/// Return the character and marker name from the `#[tile('S', marker = "start")]` attribute.
+fn tile_attribute(variant: &syn::Variant) -> syn::Result<(LitChar, Option<LitStr>)> {
+    variant.attrs.iter()
+        .find(|attr| attr.path().is_ident("tile"))
+        .ok_or_else(|| syn::Error::new(variant.ident.span(), "FromGridChar requires a #[tile('.')] attribute on every variant"))?
+        .parse_args_with(|input: ParseStream| {
+            let c: LitChar = input.parse()?;
+            if input.is_empty() {
+                return Ok((c, None));
+            }
+
+            input.parse::<Token![,]>()?;
+            let key: Ident = input.parse()?;
+            if key != "marker" {
+                return Err(syn::Error::new(key.span(), "Expected `marker = \"...\"`"));
+            }
+            input.parse::<Token![=]>()?;
+            Ok((c, Some(input.parse()?)))
+        })
+}
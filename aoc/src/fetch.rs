@@ -0,0 +1,40 @@
+use advent::day::input_dir;
+use advent::fetch::Client;
+
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Create a client for the site at `base_url`, caching inputs in `inputs` below the workspace root.
+///
+/// The session cookie comes from `AOC_SESSION`, or else the `.session_id` file in the root.
+pub fn client(root: &Path, base_url: &str) -> Result<Client, Box<dyn Error>> {
+    let session = match env::var("AOC_SESSION") {
+        Ok(session) => session,
+        Err(_) => fs::read_to_string(root.join(".session_id"))
+            .map_err(|e| format!("no AOC_SESSION or readable .session_id: {}", e))?,
+    };
+
+    Ok(Client::new(&session)
+        .with_base_url(base_url)
+        .with_cache_dir(root.join("inputs")))
+}
+
+/// Download the input of the day into its input directory, returning where it was written.
+///
+/// An input already there is never downloaded again.
+pub fn fetch_input(root: &Path, base_url: &str, year: u32, day: u32) -> Result<PathBuf, Box<dyn Error>> {
+    let mut client = client(root, base_url)?;
+    client.input(year, day)?;
+    Ok(client.cache_path(year, day))
+}
+
+/// Download the description of the day into its input directory as `puzzle.md`, along with its
+/// `example_N` files and `expected_answers`, returning the directory.
+pub fn fetch_puzzle(root: &Path, base_url: &str, year: u32, day: u32) -> Result<PathBuf, Box<dyn Error>> {
+    let puzzle = client(root, base_url)?.puzzle(year, day)?;
+    let dir = input_dir(root, year, day);
+    puzzle.write(&dir)?;
+    Ok(dir)
+}
@@ -0,0 +1,125 @@
+use advent::day::workspace_root;
+use advent::fetch::DEFAULT_BASE_URL;
+use clap::{Args, Parser, Subcommand};
+
+use std::io::Read;
+use std::path::PathBuf;
+use std::process;
+
+mod fetch;
+mod new;
+mod registry;
+mod submit;
+mod time;
+
+/// Advent of Code helpers.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Which solved days to run.
+#[derive(Args)]
+struct Selection {
+    /// Only run days of this year, instead of every year.
+    #[arg(long)]
+    year: Option<u32>,
+    /// Run this day, which can be repeated.
+    #[arg(long = "day", required_unless_present = "all")]
+    days: Vec<u32>,
+    /// Run every day.
+    #[arg(long, conflicts_with = "days")]
+    all: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a day crate from the template and register it in the workspace.
+    New {
+        year: u32,
+        day: u32,
+    },
+    /// Download the input of a day into `inputs/<year>/day<day>`.
+    Fetch {
+        year: u32,
+        day: u32,
+        /// The site to download from.
+        #[arg(long, default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Download the description of a day as Markdown, with its examples and their answers.
+    Puzzle {
+        year: u32,
+        day: u32,
+        /// The site to download from.
+        #[arg(long, default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Submit the answer to a part of a day, read from stdin if it is not given.
+    Submit {
+        day: u32,
+        part: u32,
+        answer: Option<String>,
+        #[arg(long, default_value_t = 2023)]
+        year: u32,
+        /// The site to submit to.
+        #[arg(long, default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Run solved days on their inputs and print the answers.
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+    },
+    /// Time each step of solved days on their inputs.
+    Time {
+        #[command(flatten)]
+        selection: Selection,
+        /// Repeat every day this many times, reporting the median and fastest runs.
+        #[arg(long, default_value_t = 1)]
+        bench: usize,
+        /// Save the timings as JSON.
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare against timings saved earlier.
+        #[arg(long)]
+        compare: Option<PathBuf>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let cwd = std::env::current_dir().expect("Current directory is accessible");
+    let root = workspace_root(&cwd).unwrap_or_else(|| {
+        eprintln!("error: no workspace Cargo.toml found above {}", cwd.display());
+        process::exit(1);
+    });
+
+    let result = match cli.command {
+        Command::New { year, day } => new::new_day(&root, year, day)
+            .map(|dir| println!("Created {}", dir.display()))
+            .map_err(Into::into),
+        Command::Fetch { year, day, base_url } => fetch::fetch_input(&root, &base_url, year, day)
+            .map(|path| println!("Wrote {}", path.display())),
+        Command::Puzzle { year, day, base_url } => fetch::fetch_puzzle(&root, &base_url, year, day)
+            .map(|dir| println!("Wrote the puzzle and examples to {}", dir.display())),
+        Command::Submit { day, part, answer, year, base_url } => {
+            let answer = answer.unwrap_or_else(|| {
+                let mut answer = String::new();
+                std::io::stdin().read_to_string(&mut answer).expect("Answer is readable from stdin");
+                answer
+            });
+            submit::submit_answer(&root, &base_url, year, day, part, &answer)
+                .map(|verdict| println!("{}", verdict))
+        },
+        Command::Run { selection } => time::run_days(&root, selection.year, &selection.days),
+        Command::Time { selection, bench, save, compare } => time::time_days(&root, selection.year, &selection.days, bench, save.as_deref(), compare.as_deref()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
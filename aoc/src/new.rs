@@ -0,0 +1,178 @@
+use advent::day::input_dir;
+
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.template");
+
+/// Create the crate for a day at `<year>/day<day>` under the workspace root, returning its
+/// directory.
+///
+/// The crate gets its manifest, `lib.rs` and `main.rs` from the templates, and is added to the
+/// workspace members and to the `aoc` registry. Empty `test_input` and `expected_answers` files
+/// are added to its input directory, for `advent::examples!` to read. An existing day is never
+/// touched.
+pub fn new_day(root: &Path, year: u32, day: u32) -> io::Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(ErrorKind::InvalidInput, format!("day {} is not between 1 and 25", day)));
+    }
+
+    let member = format!("{}/day{}", year, day);
+    let dir = root.join(&member);
+    if dir.exists() {
+        return Err(io::Error::new(ErrorKind::AlreadyExists, format!("{} already exists", dir.display())));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = register_member(&fs::read_to_string(&manifest_path)?, &member)
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "workspace manifest has no `members = [` list"))?;
+    let aoc_manifest_path = root.join("aoc").join("Cargo.toml");
+    let aoc_manifest = register_dependency(&fs::read_to_string(&aoc_manifest_path)?, year, day);
+    let registry_path = root.join("aoc").join("src").join("registry.rs");
+    let registry = register_solution(&fs::read_to_string(&registry_path)?, year, day)
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "registry has no closing `registry` line"))?;
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), render(CARGO_TEMPLATE, year, day))?;
+    fs::write(dir.join("src").join("lib.rs"), render(LIB_TEMPLATE, year, day))?;
+    fs::write(dir.join("src").join("main.rs"), render(MAIN_TEMPLATE, year, day))?;
+
+    let inputs = input_dir(root, year, day);
+    fs::create_dir_all(&inputs)?;
+    for file in ["test_input", "expected_answers"] {
+        if !inputs.join(file).exists() {
+            fs::write(inputs.join(file), "")?;
+        }
+    }
+
+    fs::write(&manifest_path, manifest)?;
+    fs::write(&aoc_manifest_path, aoc_manifest)?;
+    fs::write(&registry_path, registry)?;
+
+    Ok(dir)
+}
+
+/// Replace the `{{year}}` and `{{day}}` placeholders in the template.
+pub fn render(template: &str, year: u32, day: u32) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Add the member to the workspace manifest's `members` list, if it is not already there.
+///
+/// Returns `None` if the manifest has no multi-line `members = [` list.
+pub fn register_member(manifest: &str, member: &str) -> Option<String> {
+    let lines: Vec<&str> = manifest.lines().collect();
+    let start = lines.iter().position(|l| l.trim_start().starts_with("members = ["))?;
+    let end = start + lines[start..].iter().position(|l| l.trim() == "]")?;
+
+    let entry = format!("\"{}\",", member);
+    if lines[start + 1..end].iter().any(|l| l.trim() == entry) {
+        return Some(manifest.to_string());
+    }
+
+    let mut registered: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    registered.insert(end, format!("    {}", entry));
+    Some(registered.join("\n") + "\n")
+}
+
+/// Add the day crate as a dependency of `aoc`, before its dev-dependencies if it has any.
+pub fn register_dependency(manifest: &str, year: u32, day: u32) -> String {
+    let dependency = format!("[dependencies.aoc{year}-day{day}]\npath = \"../{year}/day{day}\"\n\n", year = year, day = day);
+    match manifest.find("[dev-dependencies]") {
+        Some(i) => format!("{}{}{}", &manifest[..i], dependency, &manifest[i..]),
+        None => format!("{}\n\n{}", manifest.trim_end(), dependency.trim_end()) + "\n",
+    }
+}
+
+/// Add the day to the registry source, before the line returning `registry`.
+///
+/// Returns `None` if there is no such line.
+pub fn register_solution(registry: &str, year: u32, day: u32) -> Option<String> {
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    let end = lines.iter().rposition(|l| l.trim() == "registry")?;
+    lines.insert(end, format!("    registry.register::<aoc{year}_day{day}::Day{day}>({year}, {day});", year = year, day = day));
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[workspace]\nmembers = [\n    \"advent\",\n    \"day1\",\n]\n";
+
+    #[test]
+    fn render_replaces_placeholders() {
+        assert_eq!(render("{{year}}/day/{{day}} day{{day}}", 2023, 7), "2023/day/7 day7");
+    }
+
+    #[test]
+    fn register_member_appends_once() {
+        let manifest = register_member(MANIFEST, "day2").unwrap();
+        assert_eq!(manifest, "[workspace]\nmembers = [\n    \"advent\",\n    \"day1\",\n    \"day2\",\n]\n");
+        assert_eq!(register_member(&manifest, "day2").unwrap(), manifest);
+        assert_eq!(register_member("[workspace]\n", "day2"), None);
+    }
+
+    #[test]
+    fn register_dependency_goes_before_dev_dependencies() {
+        let manifest = "[dependencies]\nclap = \"4\"\n\n[dev-dependencies]\ntempfile = \"3\"\n";
+        assert_eq!(register_dependency(manifest, 2022, 3),
+            "[dependencies]\nclap = \"4\"\n\n[dependencies.aoc2022-day3]\npath = \"../2022/day3\"\n\n[dev-dependencies]\ntempfile = \"3\"\n");
+        assert_eq!(register_dependency("[dependencies]\n", 2022, 3),
+            "[dependencies]\n\n[dependencies.aoc2022-day3]\npath = \"../2022/day3\"\n");
+    }
+
+    #[test]
+    fn register_solution_adds_line_before_return() {
+        let registry = "fn registry() -> Registry {\n    let mut registry = Registry::new();\n    registry\n}\n";
+        assert_eq!(register_solution(registry, 2022, 3).unwrap(),
+            "fn registry() -> Registry {\n    let mut registry = Registry::new();\n    registry.register::<aoc2022_day3::Day3>(2022, 3);\n    registry\n}\n");
+        assert_eq!(register_solution("fn registry() {}\n", 2022, 3), None);
+    }
+
+    /// Create a workspace root with the files `new_day` registers days in.
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("Cargo.toml"), MANIFEST).unwrap();
+        fs::create_dir_all(root.path().join("aoc/src")).unwrap();
+        fs::write(root.path().join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(root.path().join("aoc/src/registry.rs"), "fn registry() -> Registry {\n    registry\n}\n").unwrap();
+        root
+    }
+
+    #[test]
+    fn new_day_creates_crate_and_refuses_to_overwrite() {
+        let root = workspace();
+
+        let dir = new_day(root.path(), 2022, 2).unwrap();
+        assert_eq!(dir, root.path().join("2022/day2"));
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("https://adventofcode.com/2022/day/2"));
+        assert!(!lib.lines().any(|line| line.starts_with("advent::examples!")), "empty examples are not tests yet");
+        assert!(fs::read_to_string(dir.join("src/main.rs")).unwrap().contains("use aoc2022_day2::Day2;"));
+        assert!(fs::read_to_string(dir.join("Cargo.toml")).unwrap().contains("name = \"aoc2022-day2\""));
+
+        let inputs = root.path().join("inputs/2022/day2");
+        assert_eq!(fs::read_to_string(inputs.join("test_input")).unwrap(), "");
+        assert_eq!(fs::read_to_string(inputs.join("expected_answers")).unwrap(), "");
+        assert!(fs::read_to_string(root.path().join("Cargo.toml")).unwrap().contains("\"2022/day2\","));
+        assert!(fs::read_to_string(root.path().join("aoc/Cargo.toml")).unwrap().contains("[dependencies.aoc2022-day2]"));
+        assert!(fs::read_to_string(root.path().join("aoc/src/registry.rs")).unwrap().contains("(2022, 2);"));
+
+        fs::write(inputs.join("test_input"), "kept").unwrap();
+        let error = new_day(root.path(), 2022, 2).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(inputs.join("test_input")).unwrap(), "kept");
+    }
+
+    #[test]
+    fn new_day_rejects_invalid_day() {
+        let root = tempfile::tempdir().unwrap();
+        assert_eq!(new_day(root.path(), 2023, 26).unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,17 @@
+use advent::registry::Registry;
+
+/// Every solved day of every year, which `aoc new` adds each day it creates to.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register::<aoc2023_day1::Day1>(2023, 1);
+    registry.register::<aoc2023_day2::Day2>(2023, 2);
+    registry.register::<aoc2023_day3::Day3>(2023, 3);
+    registry.register::<aoc2023_day4::Day4>(2023, 4);
+    registry.register::<aoc2023_day5::Day5>(2023, 5);
+    registry.register::<aoc2023_day6::Day6>(2023, 6);
+    registry.register::<aoc2023_day7::Day7>(2023, 7);
+    registry.register::<aoc2023_day8::Day8>(2023, 8);
+    registry.register::<aoc2023_day9::Day9>(2023, 9);
+    registry.register::<aoc2023_day10::Day10>(2023, 10);
+    registry
+}
@@ -0,0 +1,26 @@
+use advent::submit::{GuessHistory, Verdict};
+
+use std::error::Error;
+use std::path::Path;
+
+use crate::fetch::client;
+
+/// Submit the answer to a part of the day, unless the guess history already knows it is wrong.
+///
+/// Every submitted guess is recorded in `guesses.json` in the workspace root.
+pub fn submit_answer(root: &Path, base_url: &str, year: u32, day: u32, part: u32, answer: &str) -> Result<Verdict, Box<dyn Error>> {
+    if !(1..=2).contains(&part) {
+        return Err(format!("part {} is not 1 or 2", part).into());
+    }
+
+    let answer = answer.trim();
+    let history_path = root.join("guesses.json");
+    let mut history = GuessHistory::load(&history_path)?;
+    history.check(year, day, part, answer)
+        .map_err(|refusal| format!("not submitting {}: {}", answer, refusal))?;
+
+    let verdict = client(root, base_url)?.submit(year, day, part, answer)?;
+    history.record(year, day, part, answer, verdict.clone());
+    history.save(&history_path)?;
+    Ok(verdict)
+}
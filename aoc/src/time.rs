@@ -0,0 +1,54 @@
+use advent::day::input_dir;
+use advent::timing::{self, Report};
+
+use std::error::Error;
+use std::path::Path;
+
+use crate::registry::registry;
+
+/// Run the selected days on their inputs `runs` times each, skipping days without an input.
+fn reports(root: &Path, year: Option<u32>, days: &[u32], runs: usize) -> Vec<Report> {
+    registry().select(year, days)
+        .filter_map(|solution| {
+            let path = input_dir(root, solution.year(), solution.day()).join("input");
+            let report = solution.bench_file(&path, runs);
+            if report.is_none() {
+                eprintln!("skipping {}: no input at {}", solution.label(), path.display());
+            }
+            report
+        })
+        .collect()
+}
+
+/// Run the selected days on their inputs and print their answers.
+pub fn run_days(root: &Path, year: Option<u32>, days: &[u32]) -> Result<(), Box<dyn Error>> {
+    let reports = reports(root, year, days, 1);
+    if reports.is_empty() {
+        return Err("no solved days with inputs were selected".into());
+    }
+
+    for report in reports {
+        println!("{:<10} {:>16} {:>16}", report.label, report.part_one, report.part_two);
+    }
+    Ok(())
+}
+
+/// Run the selected days on their inputs `runs` times and print a table of the timings.
+///
+/// The reports can be saved as JSON, and compared against reports saved earlier, such as from
+/// another commit.
+pub fn time_days(root: &Path, year: Option<u32>, days: &[u32], runs: usize, save: Option<&Path>, compare: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let reports = reports(root, year, days, runs);
+    print!("{}", timing::table(&reports));
+
+    if let Some(path) = compare {
+        let previous = timing::load(path)?;
+        println!();
+        print!("{}", timing::compare(&previous, &reports, 0.1));
+    }
+
+    if let Some(path) = save {
+        timing::save(path, &reports)?;
+    }
+    Ok(())
+}